@@ -0,0 +1,43 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{parse_quote, DeriveInput, Ident, Path};
+
+pub(crate) fn impl_arbitrary(
+    ast: &DeriveInput,
+    crate_path: &Path,
+    variant_idents: &[Ident],
+) -> TokenStream {
+    let name = &ast.ident;
+    let (_, ty_generics, where_clause) = ast.generics.split_for_impl();
+
+    let mut generics = ast.generics.clone();
+    generics.params.insert(0, parse_quote!('arbitrary));
+    let (impl_generics, ..) = generics.split_for_impl();
+
+    let arbitrary = quote!(#crate_path::__private::arbitrary);
+
+    let variant_indexes = 0..variant_idents.len();
+    let max_index = variant_idents.len() - 1;
+
+    // `int_in_range` consumes just enough bytes to cover the largest index
+    let size = ((usize::BITS - max_index.leading_zeros()) as usize + 7) / 8;
+
+    quote! {
+        impl #impl_generics #arbitrary::Arbitrary<'arbitrary> for #name #ty_generics #where_clause {
+            #[inline]
+            fn arbitrary(u: &mut #arbitrary::Unstructured<'arbitrary>) -> #arbitrary::Result<Self> {
+                Ok(match u.int_in_range(0usize..=#max_index)? {
+                    #(
+                        #variant_indexes => Self::#variant_idents,
+                    )*
+                    _ => unreachable!(),
+                })
+            }
+
+            #[inline]
+            fn size_hint(_depth: usize) -> (usize, Option<usize>) {
+                (#size, Some(#size))
+            }
+        }
+    }
+}
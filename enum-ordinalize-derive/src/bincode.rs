@@ -0,0 +1,84 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{parse_quote, DeriveInput, Path};
+
+use crate::encoding::{EncodeMode, Encoding};
+
+pub(crate) fn impl_bincode(
+    ast: &DeriveInput,
+    crate_path: &Path,
+    encode_mode: &EncodeMode,
+    encoding: &Encoding,
+    variant_count: usize,
+) -> TokenStream {
+    let name = &ast.ident;
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+
+    let mut decode_generics = ast.generics.clone();
+    decode_generics.params.push(parse_quote!(Context));
+    let (decode_impl_generics, ..) = decode_generics.split_for_impl();
+
+    let mut borrow_decode_generics = decode_generics.clone();
+    borrow_decode_generics.params.insert(0, parse_quote!('de));
+    let (borrow_decode_impl_generics, ..) = borrow_decode_generics.split_for_impl();
+
+    let bincode = quote!(#crate_path::__private::bincode);
+
+    let Encoding {
+        ty,
+        to,
+        from,
+    } = encoding;
+
+    let error = match encode_mode {
+        EncodeMode::Value => {
+            let error_message =
+                format!("the encoded value does not correspond to any variant of `{name}`");
+
+            quote!(#bincode::error::DecodeError::Other(#error_message))
+        },
+        EncodeMode::Index => {
+            let max_index = variant_count as u32 - 1;
+
+            quote! {
+                #bincode::error::DecodeError::UnexpectedVariant {
+                    type_name: ::core::any::type_name::<Self>(),
+                    allowed:   &#bincode::error::AllowedEnumVariants::Range {
+                        min: 0, max: #max_index
+                    },
+                    found:     value as u32,
+                }
+            }
+        },
+    };
+
+    quote! {
+        impl #impl_generics #bincode::Encode for #name #ty_generics #where_clause {
+            #[inline]
+            fn encode<E: #bincode::enc::Encoder>(&self, encoder: &mut E) -> ::core::result::Result<(), #bincode::error::EncodeError> {
+                let value: #ty = #to;
+
+                #bincode::Encode::encode(&value, encoder)
+            }
+        }
+
+        impl #decode_impl_generics #bincode::Decode<Context> for #name #ty_generics #where_clause {
+            #[inline]
+            fn decode<D: #bincode::de::Decoder<Context = Context>>(decoder: &mut D) -> ::core::result::Result<Self, #bincode::error::DecodeError> {
+                let value: #ty = #bincode::Decode::decode(decoder)?;
+
+                match #from {
+                    Some(variant) => Ok(variant),
+                    None => Err(#error),
+                }
+            }
+        }
+
+        impl #borrow_decode_impl_generics #bincode::BorrowDecode<'de, Context> for #name #ty_generics #where_clause {
+            #[inline]
+            fn borrow_decode<D: #bincode::de::BorrowDecoder<'de, Context = Context>>(decoder: &mut D) -> ::core::result::Result<Self, #bincode::error::DecodeError> {
+                #bincode::Decode::decode(decoder)
+            }
+        }
+    }
+}
@@ -0,0 +1,43 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{DeriveInput, Path};
+
+use crate::encoding::Encoding;
+
+pub(crate) fn impl_borsh(ast: &DeriveInput, crate_path: &Path, encoding: &Encoding) -> TokenStream {
+    let name = &ast.ident;
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+
+    let borsh = quote!(#crate_path::__private::borsh);
+
+    let Encoding {
+        ty,
+        to,
+        from,
+    } = encoding;
+
+    let error_message = format!("the encoded value does not correspond to any variant of `{name}`");
+
+    quote! {
+        impl #impl_generics #borsh::BorshSerialize for #name #ty_generics #where_clause {
+            #[inline]
+            fn serialize<W: #borsh::io::Write>(&self, writer: &mut W) -> #borsh::io::Result<()> {
+                let value: #ty = #to;
+
+                #borsh::BorshSerialize::serialize(&value, writer)
+            }
+        }
+
+        impl #impl_generics #borsh::BorshDeserialize for #name #ty_generics #where_clause {
+            #[inline]
+            fn deserialize_reader<R: #borsh::io::Read>(reader: &mut R) -> #borsh::io::Result<Self> {
+                let value: #ty = #borsh::BorshDeserialize::deserialize_reader(reader)?;
+
+                match #from {
+                    Some(variant) => Ok(variant),
+                    None => Err(#borsh::io::Error::new(#borsh::io::ErrorKind::InvalidData, #error_message)),
+                }
+            }
+        }
+    }
+}
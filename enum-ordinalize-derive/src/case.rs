@@ -0,0 +1,107 @@
+use alloc::{string::String, vec::Vec};
+
+/// The naming conventions which the variant names can be converted to.
+#[derive(Clone, Copy)]
+pub(crate) enum RenameRule {
+    Verbatim,
+    Lower,
+    Upper,
+    PascalCase,
+    CamelCase,
+    SnakeCase,
+    ScreamingSnakeCase,
+    KebabCase,
+}
+
+impl RenameRule {
+    #[inline]
+    pub(crate) fn from_str<S: AsRef<str>>(s: S) -> Option<RenameRule> {
+        match s.as_ref() {
+            "verbatim" => Some(RenameRule::Verbatim),
+            "lower" => Some(RenameRule::Lower),
+            "UPPER" => Some(RenameRule::Upper),
+            "PascalCase" => Some(RenameRule::PascalCase),
+            "camelCase" => Some(RenameRule::CamelCase),
+            "snake_case" => Some(RenameRule::SnakeCase),
+            "SCREAMING_SNAKE_CASE" => Some(RenameRule::ScreamingSnakeCase),
+            "kebab-case" => Some(RenameRule::KebabCase),
+            _ => None,
+        }
+    }
+
+    #[cfg(feature = "clap")]
+    pub(crate) fn apply(self, s: &str) -> String {
+        match self {
+            Self::Verbatim => String::from(s),
+            Self::Lower => s.to_lowercase(),
+            Self::Upper => s.to_uppercase(),
+            Self::PascalCase | Self::CamelCase => {
+                let mut result = String::with_capacity(s.len());
+
+                for (i, word) in split_words(s).into_iter().enumerate() {
+                    let mut chars = word.chars();
+
+                    if let Some(first) = chars.next() {
+                        if i == 0 && matches!(self, Self::CamelCase) {
+                            result.extend(first.to_lowercase());
+                        } else {
+                            result.extend(first.to_uppercase());
+                        }
+
+                        result.push_str(&chars.as_str().to_lowercase());
+                    }
+                }
+
+                result
+            },
+            Self::SnakeCase => split_words(s).join("_").to_lowercase(),
+            Self::ScreamingSnakeCase => to_screaming_snake_case(s),
+            Self::KebabCase => split_words(s).join("-").to_lowercase(),
+        }
+    }
+}
+
+/// Split an identifier in `PascalCase` (or any other case) into words.
+///
+/// A word boundary is placed at an underscore, and before an uppercase letter which follows a lowercase letter or a digit, or which is followed by a lowercase letter after another uppercase letter, so `HTTPServer2Error` becomes `HTTP`, `Server2` and `Error`.
+fn split_words(s: &str) -> Vec<String> {
+    let chars: Vec<char> = s.chars().collect();
+
+    let mut words = Vec::new();
+    let mut word = String::new();
+
+    for (i, c) in chars.iter().copied().enumerate() {
+        if c == '_' {
+            if !word.is_empty() {
+                words.push(core::mem::take(&mut word));
+            }
+
+            continue;
+        }
+
+        if c.is_uppercase() && i > 0 && !word.is_empty() {
+            let prev = chars[i - 1];
+            let next_is_lowercase = matches!(chars.get(i + 1), Some(next) if next.is_lowercase());
+
+            if prev.is_lowercase()
+                || prev.is_ascii_digit()
+                || (prev.is_uppercase() && next_is_lowercase)
+            {
+                words.push(core::mem::take(&mut word));
+            }
+        }
+
+        word.push(c);
+    }
+
+    if !word.is_empty() {
+        words.push(word);
+    }
+
+    words
+}
+
+/// Convert an identifier in `PascalCase` (or any other case) to `SCREAMING_SNAKE_CASE`, so `HTTPServer2Error` becomes `HTTP_SERVER2_ERROR`.
+pub(crate) fn to_screaming_snake_case(s: &str) -> String {
+    split_words(s).join("_").to_uppercase()
+}
@@ -0,0 +1,134 @@
+#![cfg_attr(not(feature = "clap"), allow(dead_code, unused_imports))]
+
+use alloc::{string::ToString, vec::Vec};
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{
+    ext::IdentExt, punctuated::Punctuated, DeriveInput, Expr, Ident, Lit, Meta, Path, Token,
+};
+
+use crate::{
+    case::RenameRule, int_wrapper::IntWrapper, panic, variant_attributes::VariantAttributes,
+};
+
+/// The options of the `clap` attribute.
+pub(crate) struct ClapOptions {
+    pub(crate) rename_all: RenameRule,
+    pub(crate) ordinal:    bool,
+}
+
+impl ClapOptions {
+    /// Parse `clap` or `clap(rename_all = "...", ordinal)`.
+    pub(crate) fn parse(meta: &Meta, ident: &Ident) -> syn::Result<Self> {
+        let mut options = ClapOptions {
+            rename_all: RenameRule::KebabCase, ordinal: false
+        };
+
+        match meta {
+            Meta::Path(_) => (),
+            Meta::List(list) => {
+                let result =
+                    list.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)?;
+
+                for meta in result {
+                    match &meta {
+                        Meta::Path(path) if path.is_ident("ordinal") => {
+                            options.ordinal = true;
+                        },
+                        Meta::NameValue(meta) if meta.path.is_ident("rename_all") => {
+                            if let Expr::Lit(lit) = &meta.value {
+                                if let Lit::Str(value) = &lit.lit {
+                                    options.rename_all = RenameRule::from_str(value.value())
+                                        .ok_or_else(|| panic::rename_all_usage(value.span()))?;
+
+                                    continue;
+                                }
+                            }
+
+                            return Err(panic::rename_all_usage(ident.span()));
+                        },
+                        _ => return Err(panic::clap_attribute_usage(ident.span())),
+                    }
+                }
+            },
+            Meta::NameValue(_) => return Err(panic::clap_attribute_usage(ident.span())),
+        }
+
+        Ok(options)
+    }
+}
+
+#[cfg(feature = "clap")]
+pub(crate) fn impl_value_enum(
+    ast: &DeriveInput,
+    crate_path: &Path,
+    options: &ClapOptions,
+    values: &[IntWrapper],
+    variant_idents: &[Ident],
+    variant_attributes: &[VariantAttributes],
+) -> syn::Result<TokenStream> {
+    let name = &ast.ident;
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+
+    let clap = quote!(#crate_path::__private::clap);
+
+    let mut possible_values: Vec<TokenStream> = Vec::with_capacity(variant_idents.len());
+    let mut names = Vec::with_capacity(variant_idents.len());
+
+    for ((variant_ident, value), attributes) in
+        variant_idents.iter().zip(values).zip(variant_attributes)
+    {
+        let variant_name = match &attributes.clap_name {
+            Some(variant_name) => variant_name.clone(),
+            None => options.rename_all.apply(&variant_ident.unraw().to_string()),
+        };
+
+        if names.contains(&variant_name) {
+            return Err(panic::duplicate_possible_value(&variant_name, variant_ident.span()));
+        }
+
+        let mut possible_value = quote!(#clap::builder::PossibleValue::new(#variant_name));
+
+        // clap only borrows `'static` strings, so the ordinals must be known at this point
+        if options.ordinal {
+            let ordinal = match value {
+                IntWrapper::Integer(value) => value.to_string(),
+                IntWrapper::Constant(..) => {
+                    return Err(panic::clap_ordinal_not_literal(variant_ident.span()))
+                },
+            };
+
+            possible_value.extend(quote!(.alias(#ordinal)));
+        }
+
+        if let Some(help) = &attributes.help {
+            possible_value.extend(quote!(.help(#help)));
+        }
+
+        if attributes.clap_hide {
+            possible_value.extend(quote!(.hide(true)));
+        }
+
+        possible_values.push(possible_value);
+        names.push(variant_name);
+    }
+
+    Ok(quote! {
+        impl #impl_generics #clap::ValueEnum for #name #ty_generics #where_clause {
+            #[inline]
+            fn value_variants<'a>() -> &'a [Self] {
+                &[#( Self::#variant_idents, )*]
+            }
+
+            #[inline]
+            fn to_possible_value(&self) -> ::core::option::Option<#clap::builder::PossibleValue> {
+                ::core::option::Option::Some(match self {
+                    #(
+                        Self::#variant_idents => #possible_values,
+                    )*
+                })
+            }
+        }
+    })
+}
@@ -0,0 +1,114 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{DeriveInput, Ident};
+
+use crate::{int_wrapper::IntWrapper, variant_type::VariantType};
+
+pub(crate) enum OrdMode {
+    Value,
+    Declaration,
+}
+
+impl OrdMode {
+    #[inline]
+    pub(crate) fn from_str<S: AsRef<str>>(s: S) -> Option<OrdMode> {
+        match s.as_ref() {
+            "value" => Some(OrdMode::Value),
+            "declaration" => Some(OrdMode::Declaration),
+            _ => None,
+        }
+    }
+}
+
+pub(crate) fn impl_ord(
+    ast: &DeriveInput,
+    ord_mode: &OrdMode,
+    variant_type: &VariantType,
+    values: &[IntWrapper],
+    variant_idents: &[Ident],
+) -> TokenStream {
+    let name = &ast.ident;
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+
+    let key = match ord_mode {
+        OrdMode::Value => quote! {
+            let key = |variant: &Self| -> #variant_type {
+                match variant {
+                    #(
+                        Self::#variant_idents => #values,
+                    )*
+                }
+            };
+        },
+        OrdMode::Declaration => {
+            let variant_indexes = 0..variant_idents.len();
+
+            quote! {
+                let key = |variant: &Self| -> usize {
+                    match variant {
+                        #(
+                            Self::#variant_idents => #variant_indexes,
+                        )*
+                    }
+                };
+            }
+        },
+    };
+
+    quote! {
+        impl #impl_generics ::core::cmp::PartialOrd for #name #ty_generics #where_clause {
+            #[inline]
+            fn partial_cmp(&self, other: &Self) -> Option<::core::cmp::Ordering> {
+                Some(::core::cmp::Ord::cmp(self, other))
+            }
+        }
+
+        impl #impl_generics ::core::cmp::Ord for #name #ty_generics #where_clause {
+            #[inline]
+            fn cmp(&self, other: &Self) -> ::core::cmp::Ordering {
+                #key
+
+                ::core::cmp::Ord::cmp(&key(self), &key(other))
+            }
+        }
+    }
+}
+
+pub(crate) fn impl_hash(
+    ast: &DeriveInput,
+    variant_type: &VariantType,
+    values: &[IntWrapper],
+    variant_idents: &[Ident],
+) -> TokenStream {
+    let name = &ast.ident;
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+
+    quote! {
+        impl #impl_generics ::core::hash::Hash for #name #ty_generics #where_clause {
+            #[inline]
+            fn hash<H: ::core::hash::Hasher>(&self, state: &mut H) {
+                let ordinal: #variant_type = match self {
+                    #(
+                        Self::#variant_idents => #values,
+                    )*
+                };
+
+                ::core::hash::Hash::hash(&ordinal, state)
+            }
+        }
+    }
+}
+
+pub(crate) fn impl_default(ast: &DeriveInput, variant_ident: &Ident) -> TokenStream {
+    let name = &ast.ident;
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+
+    quote! {
+        impl #impl_generics ::core::default::Default for #name #ty_generics #where_clause {
+            #[inline]
+            fn default() -> Self {
+                Self::#variant_ident
+            }
+        }
+    }
+}
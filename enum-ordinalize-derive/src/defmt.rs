@@ -0,0 +1,75 @@
+#![cfg_attr(not(feature = "defmt"), allow(dead_code, unused_imports))]
+
+use alloc::{string::ToString, vec::Vec};
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{ext::IdentExt, DeriveInput, Ident, Path};
+
+use crate::{int_wrapper::IntWrapper, variant_type::VariantType};
+
+/// What is logged for a variant.
+pub(crate) enum DefmtMode {
+    Name,
+    Ordinal,
+}
+
+impl DefmtMode {
+    #[inline]
+    pub(crate) fn from_str<S: AsRef<str>>(s: S) -> Option<DefmtMode> {
+        match s.as_ref() {
+            "name" => Some(DefmtMode::Name),
+            "ordinal" => Some(DefmtMode::Ordinal),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "defmt")]
+pub(crate) fn impl_format(
+    ast: &DeriveInput,
+    crate_path: &Path,
+    defmt_mode: &DefmtMode,
+    variant_type: &VariantType,
+    values: &[IntWrapper],
+    variant_idents: &[Ident],
+) -> TokenStream {
+    let name = &ast.ident;
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+
+    let defmt = quote!(#crate_path::__private::defmt);
+
+    let format = match defmt_mode {
+        DefmtMode::Name => {
+            let variant_names: Vec<_> =
+                variant_idents.iter().map(|ident| ident.unraw().to_string()).collect();
+
+            // the names are interned, so only their indexes are transmitted
+            quote! {
+                match self {
+                    #(
+                        Self::#variant_idents => #defmt::write!(f, #variant_names),
+                    )*
+                }
+            }
+        },
+        DefmtMode::Ordinal => quote! {
+            let ordinal: #variant_type = match self {
+                #(
+                    Self::#variant_idents => #values,
+                )*
+            };
+
+            <#variant_type as #defmt::Format>::format(&ordinal, f)
+        },
+    };
+
+    quote! {
+        impl #impl_generics #defmt::Format for #name #ty_generics #where_clause {
+            #[inline]
+            fn format(&self, f: #defmt::Formatter<'_>) {
+                #format
+            }
+        }
+    }
+}
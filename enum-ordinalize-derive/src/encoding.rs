@@ -0,0 +1,101 @@
+#![cfg_attr(not(any(feature = "borsh", feature = "bincode")), allow(dead_code, unused_imports))]
+
+use alloc::vec::Vec;
+
+use proc_macro2::{Literal, TokenStream};
+use quote::quote;
+use syn::Ident;
+
+use crate::{int_wrapper::IntWrapper, variant_type::VariantType};
+
+/// How the variants are encoded by serialization frameworks.
+pub(crate) enum EncodeMode {
+    Value,
+    Index,
+}
+
+impl EncodeMode {
+    #[inline]
+    pub(crate) fn from_str<S: AsRef<str>>(s: S) -> Option<EncodeMode> {
+        match s.as_ref() {
+            "value" => Some(EncodeMode::Value),
+            "index" => Some(EncodeMode::Index),
+            _ => None,
+        }
+    }
+}
+
+/// The integer type of an encoded variant, and the expressions converting between variants and the integers.
+pub(crate) struct Encoding {
+    /// The integer type.
+    pub(crate) ty:   TokenStream,
+    /// An expression of the integer type, which encodes `self`.
+    pub(crate) to:   TokenStream,
+    /// An expression of `Option<Self>`, which decodes the integer variable `value`.
+    pub(crate) from: TokenStream,
+}
+
+impl Encoding {
+    pub(crate) fn new(
+        encode_mode: &EncodeMode,
+        variant_type: &VariantType,
+        values: &[IntWrapper],
+        variant_idents: &[Ident],
+    ) -> Self {
+        match encode_mode {
+            EncodeMode::Value => Self {
+                ty:   quote!(#variant_type),
+                to:   quote! {
+                    match self {
+                        #(
+                            Self::#variant_idents => #values,
+                        )*
+                    }
+                },
+                // the values can be constant expressions which are not allowed in patterns
+                from: quote! {
+                    if false {
+                        unreachable!()
+                    } #( else if value == #values {
+                        Some(Self::#variant_idents)
+                    } )* else {
+                        None
+                    }
+                },
+            },
+            EncodeMode::Index => {
+                let variant_count = variant_idents.len();
+
+                let ty = if variant_count <= 1 << 8 {
+                    quote!(u8)
+                } else if variant_count <= 1 << 16 {
+                    quote!(u16)
+                } else {
+                    quote!(u32)
+                };
+
+                let variant_indexes =
+                    (0..variant_count).map(Literal::usize_unsuffixed).collect::<Vec<_>>();
+
+                Self {
+                    to: quote! {
+                        match self {
+                            #(
+                                Self::#variant_idents => #variant_indexes,
+                            )*
+                        }
+                    },
+                    from: quote! {
+                        match value {
+                            #(
+                                #variant_indexes => Some(Self::#variant_idents),
+                            )*
+                            _ => None,
+                        }
+                    },
+                    ty,
+                }
+            },
+        }
+    }
+}
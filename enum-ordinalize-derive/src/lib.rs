@@ -9,9 +9,32 @@ This library enables enums to not only obtain the ordinal values of their varian
 #[macro_use]
 extern crate alloc;
 
+#[cfg(feature = "arbitrary")]
+mod arbitrary;
+#[cfg(feature = "bincode")]
+mod bincode;
+#[cfg(feature = "borsh")]
+mod borsh;
+mod case;
+mod clap;
+mod core_impls;
+mod defmt;
+mod encoding;
 mod int128;
 mod int_wrapper;
+#[cfg(feature = "modular-bitfield")]
+mod modular_bitfield;
 mod panic;
+mod protobuf;
+#[cfg(feature = "rand")]
+mod rand;
+#[cfg(feature = "rkyv")]
+mod rkyv;
+mod schema;
+mod sql;
+#[cfg(feature = "valuable")]
+mod valuable;
+mod variant_attributes;
 mod variant_type;
 
 use alloc::{string::ToString, vec::Vec};
@@ -23,11 +46,41 @@ use syn::{
     parse_macro_input,
     punctuated::Punctuated,
     spanned::Spanned,
-    Data, DeriveInput, Expr, Fields, Ident, Lit, Meta, Token, UnOp, Visibility,
+    Data, DeriveInput, Expr, Fields, Ident, Lit, Meta, Path, Token, UnOp, Visibility,
 };
 use variant_type::VariantType;
 
-use crate::{int128::Int128, int_wrapper::IntWrapper};
+use crate::{
+    clap::ClapOptions, core_impls::OrdMode, defmt::DefmtMode, encoding::EncodeMode, int128::Int128,
+    int_wrapper::IntWrapper, protobuf::ProtobufSyntax, schema::SchemaMode, sql::SqlMode,
+    variant_attributes::VariantAttributes,
+};
+
+/// Return the features of `enum-ordinalize`, one of which a sub-attribute of the `ordinalize` attribute needs but none is enabled.
+fn missing_features(name: &str) -> Option<&'static str> {
+    match name {
+        "impl_step" | "bits" if !cfg!(feature = "traits") => Some("`traits`"),
+        "rand" if !cfg!(feature = "rand") => Some("`rand`"),
+        "arbitrary" if !cfg!(feature = "arbitrary") => Some("`arbitrary`"),
+        "borsh" if !cfg!(feature = "borsh") => Some("`borsh`"),
+        "bincode" if !cfg!(feature = "bincode") => Some("`bincode`"),
+        "encode" if !cfg!(any(feature = "borsh", feature = "bincode")) => {
+            Some("`borsh` or `bincode`")
+        },
+        "rkyv" if !cfg!(feature = "rkyv") => Some("`rkyv`"),
+        "sql" if !cfg!(any(feature = "rusqlite", feature = "diesel")) => {
+            Some("`rusqlite` or `diesel`")
+        },
+        "schema" if !cfg!(any(feature = "schemars", feature = "utoipa")) => {
+            Some("`schemars` or `utoipa`")
+        },
+        "clap" if !cfg!(feature = "clap") => Some("`clap`"),
+        "valuable" if !cfg!(feature = "valuable") => Some("`valuable`"),
+        "defmt" if !cfg!(feature = "defmt") => Some("`defmt`"),
+        "modular_bitfield" if !cfg!(feature = "modular-bitfield") => Some("`modular-bitfield`"),
+        _ => None,
+    }
+}
 
 #[proc_macro_derive(Ordinalize, attributes(ordinalize))]
 pub fn ordinalize_derive(input: TokenStream) -> TokenStream {
@@ -136,14 +189,35 @@ pub fn ordinalize_derive(input: TokenStream) -> TokenStream {
         variant_type:               VariantType,
         values:                     Vec<IntWrapper>,
         variant_idents:             Vec<Ident>,
+        variant_attributes:         Vec<VariantAttributes>,
         use_constant_counter:       bool,
         enable_trait:               bool,
+        enable_step:                bool,
         enable_variant_count:       Option<ConstMember>,
         enable_variants:            Option<ConstMember>,
         enable_values:              Option<ConstMember>,
         enable_from_ordinal_unsafe: Option<ConstFunctionMember>,
         enable_from_ordinal:        Option<ConstFunctionMember>,
         enable_ordinal:             Option<ConstFunctionMember>,
+        crate_path:                 Path,
+        remote:                     Option<Path>,
+        ord_mode:                   Option<OrdMode>,
+        enable_hash:                bool,
+        enable_rand:                bool,
+        enable_arbitrary:           bool,
+        enable_borsh:               bool,
+        enable_bincode:             bool,
+        enable_modular_bitfield:    bool,
+        enable_valuable:            bool,
+        enable_rkyv:                bool,
+        default_variant:            Option<Ident>,
+        encode_mode:                EncodeMode,
+        bits_mode:                  Option<EncodeMode>,
+        protobuf:                   Option<ProtobufSyntax>,
+        sql_mode:                   Option<SqlMode>,
+        schema_mode:                Option<SchemaMode>,
+        clap_options:               Option<ClapOptions>,
+        defmt_mode:                 Option<DefmtMode>,
     }
 
     impl Parse for MyDeriveInput {
@@ -152,12 +226,35 @@ pub fn ordinalize_derive(input: TokenStream) -> TokenStream {
 
             let mut variant_type = VariantType::default();
             let mut enable_trait = cfg!(feature = "traits");
+            let mut enable_step = false;
             let mut enable_variant_count = None;
             let mut enable_variants = None;
             let mut enable_values = None;
             let mut enable_from_ordinal_unsafe = None;
             let mut enable_from_ordinal = None;
             let mut enable_ordinal = None;
+            let mut crate_path: Path = syn::parse_quote!(::enum_ordinalize);
+            let mut remote = None;
+            let mut ord_mode = None;
+            let mut enable_hash = false;
+            let mut enable_rand = false;
+            let mut enable_arbitrary = false;
+            let mut enable_borsh = false;
+            let mut enable_bincode = false;
+            let mut enable_modular_bitfield = false;
+            let mut enable_valuable = false;
+            let mut enable_rkyv = false;
+            let mut default_variant: Option<Option<Ident>> = None;
+            let mut encode_mode = EncodeMode::Value;
+            let mut bits_mode = None;
+            let mut protobuf = None;
+            let mut sql_mode = None;
+            let mut schema_mode = None;
+            let mut clap_options = None;
+            let mut defmt_mode = None;
+
+            // the first sub-attribute whose implementation relies on the `Ordinalize` trait
+            let mut trait_dependent: Option<Ident> = None;
 
             for attr in ast.attrs.iter() {
                 let path = attr.path();
@@ -175,8 +272,6 @@ pub fn ordinalize_derive(input: TokenStream) -> TokenStream {
                                     variant_type = VariantType::from_str(value.to_string());
                                 }
                             }
-
-                            break;
                         },
                         "ordinalize" => {
                             if let Meta::List(list) = &attr.meta {
@@ -188,13 +283,31 @@ pub fn ordinalize_derive(input: TokenStream) -> TokenStream {
                                     let path = meta.path();
 
                                     if let Some(ident) = path.get_ident() {
-                                        match ident.to_string().as_str() {
+                                        let name = ident.to_string();
+
+                                        if let Some(features) = missing_features(&name) {
+                                            return Err(panic::feature_required(
+                                                ident,
+                                                features,
+                                                ident.span(),
+                                            ));
+                                        }
+
+                                        match name.as_str() {
                                             "impl_trait" => {
                                                 if let Meta::NameValue(meta) = &meta {
                                                     if let Expr::Lit(lit) = &meta.value {
                                                         if let Lit::Bool(value) = &lit.lit {
                                                             if cfg!(feature = "traits") {
                                                                 enable_trait = value.value;
+                                                            } else if value.value {
+                                                                return Err(
+                                                                    panic::feature_required(
+                                                                        ident,
+                                                                        "`traits`",
+                                                                        ident.span(),
+                                                                    ),
+                                                                );
                                                             }
                                                         } else {
                                                             return Err(
@@ -217,6 +330,32 @@ pub fn ordinalize_derive(input: TokenStream) -> TokenStream {
                                                     ));
                                                 }
                                             },
+                                            "impl_step" => {
+                                                if let Meta::NameValue(meta) = &meta {
+                                                    if let Expr::Lit(lit) = &meta.value {
+                                                        if let Lit::Bool(value) = &lit.lit {
+                                                            enable_step = value.value;
+                                                        } else {
+                                                            return Err(
+                                                                panic::bool_attribute_usage(
+                                                                    ident,
+                                                                    ident.span(),
+                                                                ),
+                                                            );
+                                                        }
+                                                    } else {
+                                                        return Err(panic::bool_attribute_usage(
+                                                            ident,
+                                                            ident.span(),
+                                                        ));
+                                                    }
+                                                } else {
+                                                    return Err(panic::bool_attribute_usage(
+                                                        ident,
+                                                        ident.span(),
+                                                    ));
+                                                }
+                                            },
                                             "variant_count" => {
                                                 if let Meta::List(list) = &meta {
                                                     enable_variant_count = Some(list.parse_args()?);
@@ -278,6 +417,414 @@ pub fn ordinalize_derive(input: TokenStream) -> TokenStream {
                                                     ));
                                                 }
                                             },
+                                            "bits" => {
+                                                if let Meta::NameValue(meta) = &meta {
+                                                    if let Expr::Lit(lit) = &meta.value {
+                                                        if let Lit::Str(value) = &lit.lit {
+                                                            bits_mode = Some(
+                                                                EncodeMode::from_str(value.value())
+                                                                    .ok_or_else(|| {
+                                                                        panic::bits_attribute_usage(
+                                                                            value.span(),
+                                                                        )
+                                                                    })?,
+                                                            );
+                                                        } else {
+                                                            return Err(
+                                                                panic::bits_attribute_usage(
+                                                                    ident.span(),
+                                                                ),
+                                                            );
+                                                        }
+                                                    } else {
+                                                        return Err(panic::bits_attribute_usage(
+                                                            ident.span(),
+                                                        ));
+                                                    }
+                                                } else {
+                                                    return Err(panic::bits_attribute_usage(
+                                                        ident.span(),
+                                                    ));
+                                                }
+                                            },
+                                            "encode" => {
+                                                if let Meta::NameValue(meta) = &meta {
+                                                    if let Expr::Lit(lit) = &meta.value {
+                                                        if let Lit::Str(value) = &lit.lit {
+                                                            encode_mode = EncodeMode::from_str(
+                                                                value.value(),
+                                                            )
+                                                            .ok_or_else(|| {
+                                                                panic::encode_attribute_usage(
+                                                                    value.span(),
+                                                                )
+                                                            })?;
+                                                        } else {
+                                                            return Err(
+                                                                panic::encode_attribute_usage(
+                                                                    ident.span(),
+                                                                ),
+                                                            );
+                                                        }
+                                                    } else {
+                                                        return Err(panic::encode_attribute_usage(
+                                                            ident.span(),
+                                                        ));
+                                                    }
+                                                } else {
+                                                    return Err(panic::encode_attribute_usage(
+                                                        ident.span(),
+                                                    ));
+                                                }
+                                            },
+                                            "sql" => {
+                                                trait_dependent
+                                                    .get_or_insert_with(|| ident.clone());
+
+                                                if let Meta::NameValue(meta) = &meta {
+                                                    if let Expr::Lit(lit) = &meta.value {
+                                                        if let Lit::Str(value) = &lit.lit {
+                                                            sql_mode = Some(
+                                                                SqlMode::from_str(value.value())
+                                                                    .ok_or_else(|| {
+                                                                        panic::sql_attribute_usage(
+                                                                            value.span(),
+                                                                        )
+                                                                    })?,
+                                                            );
+                                                        } else {
+                                                            return Err(
+                                                                panic::sql_attribute_usage(
+                                                                    ident.span(),
+                                                                ),
+                                                            );
+                                                        }
+                                                    } else {
+                                                        return Err(panic::sql_attribute_usage(
+                                                            ident.span(),
+                                                        ));
+                                                    }
+                                                } else {
+                                                    return Err(panic::sql_attribute_usage(
+                                                        ident.span(),
+                                                    ));
+                                                }
+                                            },
+                                            "schema" => {
+                                                if let Meta::NameValue(meta) = &meta {
+                                                    if let Expr::Lit(lit) = &meta.value {
+                                                        if let Lit::Str(value) = &lit.lit {
+                                                            schema_mode = Some(
+                                                                SchemaMode::from_str(value.value())
+                                                                    .ok_or_else(|| {
+                                                                        panic::schema_attribute_usage(
+                                                                            value.span(),
+                                                                        )
+                                                                    })?,
+                                                            );
+                                                        } else {
+                                                            return Err(
+                                                                panic::schema_attribute_usage(
+                                                                    ident.span(),
+                                                                ),
+                                                            );
+                                                        }
+                                                    } else {
+                                                        return Err(panic::schema_attribute_usage(
+                                                            ident.span(),
+                                                        ));
+                                                    }
+                                                } else {
+                                                    return Err(panic::schema_attribute_usage(
+                                                        ident.span(),
+                                                    ));
+                                                }
+                                            },
+                                            "clap" => {
+                                                clap_options =
+                                                    Some(ClapOptions::parse(&meta, ident)?);
+                                            },
+                                            "defmt" => {
+                                                if let Meta::NameValue(meta) = &meta {
+                                                    if let Expr::Lit(lit) = &meta.value {
+                                                        if let Lit::Str(value) = &lit.lit {
+                                                            defmt_mode = Some(
+                                                                DefmtMode::from_str(value.value())
+                                                                    .ok_or_else(|| {
+                                                                        panic::defmt_attribute_usage(
+                                                                            value.span(),
+                                                                        )
+                                                                    })?,
+                                                            );
+                                                        } else {
+                                                            return Err(
+                                                                panic::defmt_attribute_usage(
+                                                                    ident.span(),
+                                                                ),
+                                                            );
+                                                        }
+                                                    } else {
+                                                        return Err(panic::defmt_attribute_usage(
+                                                            ident.span(),
+                                                        ));
+                                                    }
+                                                } else {
+                                                    return Err(panic::defmt_attribute_usage(
+                                                        ident.span(),
+                                                    ));
+                                                }
+                                            },
+                                            "ord" => {
+                                                if let Meta::NameValue(meta) = &meta {
+                                                    if let Expr::Lit(lit) = &meta.value {
+                                                        if let Lit::Str(value) = &lit.lit {
+                                                            ord_mode = Some(
+                                                                OrdMode::from_str(value.value())
+                                                                    .ok_or_else(|| {
+                                                                        panic::ord_attribute_usage(
+                                                                            value.span(),
+                                                                        )
+                                                                    })?,
+                                                            );
+                                                        } else {
+                                                            return Err(
+                                                                panic::ord_attribute_usage(
+                                                                    ident.span(),
+                                                                ),
+                                                            );
+                                                        }
+                                                    } else {
+                                                        return Err(panic::ord_attribute_usage(
+                                                            ident.span(),
+                                                        ));
+                                                    }
+                                                } else {
+                                                    return Err(panic::ord_attribute_usage(
+                                                        ident.span(),
+                                                    ));
+                                                }
+                                            },
+                                            "hash" => {
+                                                if let Meta::Path(_) = &meta {
+                                                    enable_hash = true;
+                                                } else {
+                                                    return Err(panic::path_attribute_usage(
+                                                        ident,
+                                                        ident.span(),
+                                                    ));
+                                                }
+                                            },
+                                            "rand" => {
+                                                if let Meta::Path(_) = &meta {
+                                                    enable_rand = true;
+                                                } else {
+                                                    return Err(panic::path_attribute_usage(
+                                                        ident,
+                                                        ident.span(),
+                                                    ));
+                                                }
+                                            },
+                                            "arbitrary" => {
+                                                if let Meta::Path(_) = &meta {
+                                                    enable_arbitrary = true;
+                                                } else {
+                                                    return Err(panic::path_attribute_usage(
+                                                        ident,
+                                                        ident.span(),
+                                                    ));
+                                                }
+                                            },
+                                            "borsh" => {
+                                                if let Meta::Path(_) = &meta {
+                                                    enable_borsh = true;
+                                                } else {
+                                                    return Err(panic::path_attribute_usage(
+                                                        ident,
+                                                        ident.span(),
+                                                    ));
+                                                }
+                                            },
+                                            "bincode" => {
+                                                if let Meta::Path(_) = &meta {
+                                                    enable_bincode = true;
+                                                } else {
+                                                    return Err(panic::path_attribute_usage(
+                                                        ident,
+                                                        ident.span(),
+                                                    ));
+                                                }
+                                            },
+                                            "modular_bitfield" => {
+                                                trait_dependent
+                                                    .get_or_insert_with(|| ident.clone());
+
+                                                // the number of bytes is a const argument, which cannot depend on generic parameters
+                                                if !ast.generics.params.is_empty() {
+                                                    return Err(panic::generics_unsupported(
+                                                        ident,
+                                                        ident.span(),
+                                                    ));
+                                                }
+
+                                                if let Meta::Path(_) = &meta {
+                                                    enable_modular_bitfield = true;
+                                                } else {
+                                                    return Err(panic::path_attribute_usage(
+                                                        ident,
+                                                        ident.span(),
+                                                    ));
+                                                }
+                                            },
+                                            "valuable" => {
+                                                if let Meta::Path(_) = &meta {
+                                                    enable_valuable = true;
+                                                } else {
+                                                    return Err(panic::path_attribute_usage(
+                                                        ident,
+                                                        ident.span(),
+                                                    ));
+                                                }
+                                            },
+                                            "rkyv" => {
+                                                trait_dependent
+                                                    .get_or_insert_with(|| ident.clone());
+
+                                                if let Meta::Path(_) = &meta {
+                                                    enable_rkyv = true;
+                                                } else {
+                                                    return Err(panic::path_attribute_usage(
+                                                        ident,
+                                                        ident.span(),
+                                                    ));
+                                                }
+                                            },
+                                            "protobuf" => match &meta {
+                                                Meta::Path(_) => {
+                                                    protobuf = Some(ProtobufSyntax::Proto2);
+                                                },
+                                                Meta::NameValue(meta) => {
+                                                    if let Expr::Lit(lit) = &meta.value {
+                                                        if let Lit::Str(value) = &lit.lit {
+                                                            protobuf = Some(
+                                                                ProtobufSyntax::from_str(
+                                                                    value.value(),
+                                                                )
+                                                                .ok_or_else(|| {
+                                                                    panic::protobuf_attribute_usage(
+                                                                        value.span(),
+                                                                    )
+                                                                })?,
+                                                            );
+                                                        } else {
+                                                            return Err(
+                                                                panic::protobuf_attribute_usage(
+                                                                    ident.span(),
+                                                                ),
+                                                            );
+                                                        }
+                                                    } else {
+                                                        return Err(
+                                                            panic::protobuf_attribute_usage(
+                                                                ident.span(),
+                                                            ),
+                                                        );
+                                                    }
+                                                },
+                                                Meta::List(_) => {
+                                                    return Err(panic::protobuf_attribute_usage(
+                                                        ident.span(),
+                                                    ));
+                                                },
+                                            },
+                                            "default" => match &meta {
+                                                Meta::Path(_) => {
+                                                    default_variant = Some(None);
+                                                },
+                                                Meta::NameValue(meta) => {
+                                                    if let Expr::Path(path) = &meta.value {
+                                                        if let Some(variant_ident) =
+                                                            path.path.get_ident()
+                                                        {
+                                                            default_variant =
+                                                                Some(Some(variant_ident.clone()));
+                                                        } else {
+                                                            return Err(
+                                                                panic::default_attribute_usage(
+                                                                    ident.span(),
+                                                                ),
+                                                            );
+                                                        }
+                                                    } else {
+                                                        return Err(
+                                                            panic::default_attribute_usage(
+                                                                ident.span(),
+                                                            ),
+                                                        );
+                                                    }
+                                                },
+                                                Meta::List(_) => {
+                                                    return Err(panic::default_attribute_usage(
+                                                        ident.span(),
+                                                    ));
+                                                },
+                                            },
+                                            "crate" => {
+                                                if let Meta::NameValue(meta) = &meta {
+                                                    match &meta.value {
+                                                        Expr::Lit(lit) => {
+                                                            if let Lit::Str(value) = &lit.lit {
+                                                                crate_path = value.parse()?;
+                                                            } else {
+                                                                return Err(
+                                                                    panic::crate_attribute_usage(
+                                                                        ident.span(),
+                                                                    ),
+                                                                );
+                                                            }
+                                                        },
+                                                        Expr::Path(path) => {
+                                                            crate_path = path.path.clone();
+                                                        },
+                                                        _ => {
+                                                            return Err(
+                                                                panic::crate_attribute_usage(
+                                                                    ident.span(),
+                                                                ),
+                                                            );
+                                                        },
+                                                    }
+                                                } else {
+                                                    return Err(panic::crate_attribute_usage(
+                                                        ident.span(),
+                                                    ));
+                                                }
+                                            },
+                                            "remote" => {
+                                                if let Meta::NameValue(meta) = &meta {
+                                                    if let Expr::Lit(lit) = &meta.value {
+                                                        if let Lit::Str(value) = &lit.lit {
+                                                            remote = Some(value.parse()?);
+                                                        } else {
+                                                            return Err(
+                                                                panic::string_attribute_usage(
+                                                                    ident,
+                                                                    ident.span(),
+                                                                ),
+                                                            );
+                                                        }
+                                                    } else {
+                                                        return Err(panic::string_attribute_usage(
+                                                            ident,
+                                                            ident.span(),
+                                                        ));
+                                                    }
+                                                } else {
+                                                    return Err(panic::string_attribute_usage(
+                                                        ident,
+                                                        ident.span(),
+                                                    ));
+                                                }
+                                            },
                                             _ => {
                                                 return Err(panic::sub_attributes_for_ordinalize(
                                                     ident.span(),
@@ -300,6 +847,10 @@ pub fn ordinalize_derive(input: TokenStream) -> TokenStream {
                 }
             }
 
+            if let (false, Some(ident)) = (enable_trait, &trait_dependent) {
+                return Err(panic::trait_required(ident, ident.span()));
+            }
+
             let name = &ast.ident;
 
             if let Data::Enum(data) = &ast.data {
@@ -524,19 +1075,61 @@ pub fn ordinalize_derive(input: TokenStream) -> TokenStream {
                     }
                 }
 
+                let default_variant = match default_variant {
+                    Some(Some(default_variant)) => {
+                        if !variant_idents.contains(&default_variant) {
+                            return Err(panic::unknown_variant(
+                                &default_variant,
+                                default_variant.span(),
+                            ));
+                        }
+
+                        Some(default_variant)
+                    },
+                    Some(None) => Some(variant_idents[0].clone()),
+                    None => None,
+                };
+
+                let variant_attributes = data
+                    .variants
+                    .iter()
+                    .map(|variant| VariantAttributes::parse(&variant.attrs))
+                    .collect::<syn::Result<Vec<_>>>()?;
+
                 Ok(MyDeriveInput {
                     ast,
                     variant_type,
                     values,
                     variant_idents,
+                    variant_attributes,
                     use_constant_counter,
                     enable_trait,
+                    enable_step,
                     enable_variant_count,
                     enable_variants,
                     enable_values,
                     enable_from_ordinal_unsafe,
                     enable_from_ordinal,
                     enable_ordinal,
+                    crate_path,
+                    remote,
+                    ord_mode,
+                    enable_hash,
+                    enable_rand,
+                    enable_arbitrary,
+                    enable_borsh,
+                    enable_bincode,
+                    enable_modular_bitfield,
+                    enable_valuable,
+                    enable_rkyv,
+                    default_variant,
+                    encode_mode,
+                    bits_mode,
+                    protobuf,
+                    sql_mode,
+                    schema_mode,
+                    clap_options,
+                    defmt_mode,
                 })
             } else {
                 Err(panic::not_enum(ast.ident.span()))
@@ -547,19 +1140,56 @@ pub fn ordinalize_derive(input: TokenStream) -> TokenStream {
     // Parse the token stream
     let derive_input = parse_macro_input!(input as MyDeriveInput);
 
+    #[cfg_attr(
+        not(all(
+            feature = "rand",
+            feature = "arbitrary",
+            feature = "borsh",
+            feature = "bincode",
+            feature = "rkyv",
+            any(feature = "rusqlite", feature = "diesel"),
+            any(feature = "schemars", feature = "utoipa"),
+            feature = "clap",
+            feature = "valuable",
+            feature = "defmt",
+            feature = "modular-bitfield"
+        )),
+        allow(unused_variables)
+    )]
     let MyDeriveInput {
         ast,
         variant_type,
         values,
         variant_idents,
+        variant_attributes,
         use_constant_counter,
         enable_trait,
+        enable_step,
         enable_variant_count,
         enable_variants,
         enable_values,
         enable_ordinal,
         enable_from_ordinal_unsafe,
         enable_from_ordinal,
+        crate_path,
+        remote,
+        ord_mode,
+        enable_hash,
+        enable_rand,
+        enable_arbitrary,
+        enable_borsh,
+        enable_bincode,
+        enable_modular_bitfield,
+        enable_valuable,
+        enable_rkyv,
+        default_variant,
+        encode_mode,
+        bits_mode,
+        protobuf,
+        sql_mode,
+        schema_mode,
+        clap_options,
+        defmt_mode,
     } = derive_input;
 
     // Get the identifier of the type.
@@ -575,6 +1205,10 @@ pub fn ordinalize_derive(input: TokenStream) -> TokenStream {
     if enable_trait {
         #[cfg(feature = "traits")]
         {
+            use syn::ext::IdentExt;
+
+            let variant_indexes: Vec<usize> = (0..variant_count).collect();
+
             let from_ordinal_unsafe = if variant_count == 1 {
                 let variant_ident = &variant_idents[0];
 
@@ -620,6 +1254,96 @@ pub fn ordinalize_derive(input: TokenStream) -> TokenStream {
                 }
             };
 
+            // the values are encoded in two's complement only if any of them is negative
+            let (value_bits, negative) = if variant_type.is_signed() {
+                let values =
+                    quote!(&[#( { let value: #variant_type = #values; value as i128 }, )*]);
+
+                (
+                    quote!(#crate_path::__private::signed_bits(#values)),
+                    quote!(#crate_path::__private::has_negative(#values)),
+                )
+            } else {
+                (
+                    quote!(#crate_path::__private::unsigned_bits(&[#( { let value: #variant_type = #values; value as u128 }, )*])),
+                    quote!(false),
+                )
+            };
+
+            // the bits are returned in a `u64`, which the values of 128-bit types may not fit in
+            let wide = matches!(variant_type, VariantType::I128 | VariantType::U128);
+
+            let bits = match bits_mode {
+                // the default implementation encodes the indexes
+                Some(EncodeMode::Index) => quote!(),
+                // fall back to the indexes if the values do not fit, which is only known at compile time for constants
+                None if wide => quote! {
+                    const BITS: u32 = {
+                        const VALUE_BITS: u32 = #value_bits;
+
+                        if VALUE_BITS <= u64::BITS {
+                            VALUE_BITS
+                        } else {
+                            #crate_path::__private::index_bits(#variant_count)
+                        }
+                    };
+
+                    #[inline]
+                    fn into_bits(&self) -> u64 {
+                        const VALUE_BITS: u32 = #value_bits;
+
+                        if VALUE_BITS <= u64::BITS {
+                            <#variant_type as #crate_path::OrdinalInt>::to_bits(Ordinalize::ordinal(self), VALUE_BITS)
+                        } else {
+                            Ordinalize::variant_index(self) as u64
+                        }
+                    }
+
+                    #[inline]
+                    fn from_bits(bits: u64) -> Option<Self> {
+                        const VALUE_BITS: u32 = #value_bits;
+                        const NEGATIVE: bool = #negative;
+
+                        if VALUE_BITS <= u64::BITS {
+                            <Self as Ordinalize>::from_ordinal(#crate_path::__private::value_from_bits(bits, VALUE_BITS, NEGATIVE)?)
+                        } else if bits.checked_shr(Self::BITS).unwrap_or(0) != 0 {
+                            None
+                        } else {
+                            <Self as Ordinalize>::from_variant_index(usize::try_from(bits).ok()?)
+                        }
+                    }
+                },
+                _ => {
+                    if wide {
+                        let message = format!(
+                            "the values of the variants of `{name}` need more than 64 bits, so \
+                             they should be encoded with the `bits = \"index\"` attribute",
+                            name = IdentExt::unraw(name)
+                        );
+
+                        expanded.extend(quote! {
+                            const _: () = ::core::assert!(#value_bits <= u64::BITS, #message);
+                        });
+                    }
+
+                    quote! {
+                        const BITS: u32 = #value_bits;
+
+                        #[inline]
+                        fn into_bits(&self) -> u64 {
+                            <#variant_type as #crate_path::OrdinalInt>::to_bits(Ordinalize::ordinal(self), Self::BITS)
+                        }
+
+                        #[inline]
+                        fn from_bits(bits: u64) -> Option<Self> {
+                            const NEGATIVE: bool = #negative;
+
+                            <Self as Ordinalize>::from_ordinal(#crate_path::__private::value_from_bits(bits, Self::BITS, NEGATIVE)?)
+                        }
+                    }
+                },
+            };
+
             expanded.extend(quote! {
                 impl #impl_generics Ordinalize for #name #ty_generics #where_clause {
                     type VariantType = #variant_type;
@@ -630,6 +1354,8 @@ pub fn ordinalize_derive(input: TokenStream) -> TokenStream {
 
                     const VALUES: &'static [#variant_type] = &[#( #values, )*];
 
+                    #bits
+
                     #[inline]
                     fn ordinal(&self) -> #variant_type {
                         match self {
@@ -642,8 +1368,77 @@ pub fn ordinalize_derive(input: TokenStream) -> TokenStream {
                     #from_ordinal_unsafe
 
                     #from_ordinal
+
+                    #[inline]
+                    fn variant_index(&self) -> usize {
+                        match self {
+                            #(
+                                Self::#variant_idents => #variant_indexes,
+                            )*
+                        }
+                    }
+
+                    #[inline]
+                    fn from_variant_index(index: usize) -> Option<Self> {
+                        match index {
+                            #(
+                                #variant_indexes => Some(Self::#variant_idents),
+                            )*
+                            _ => None
+                        }
+                    }
                 }
             });
+
+            let variant_names: Vec<_> =
+                variant_idents.iter().map(|ident| IdentExt::unraw(ident).to_string()).collect();
+
+            expanded.extend(quote! {
+                impl #impl_generics #crate_path::VariantNames for #name #ty_generics #where_clause {
+                    const VARIANT_NAMES: &'static [&'static str] = &[#( #variant_names, )*];
+
+                    #[inline]
+                    fn variant_name(&self) -> &'static str {
+                        match self {
+                            #(
+                                Self::#variant_idents => #variant_names,
+                            )*
+                        }
+                    }
+                }
+            });
+
+            if enable_step {
+                expanded.extend(quote! {
+                    impl #impl_generics ::core::iter::Step for #name #ty_generics #where_clause {
+                        #[inline]
+                        fn steps_between(start: &Self, end: &Self) -> (usize, Option<usize>) {
+                            let start = Ordinalize::variant_index(start);
+                            let end = Ordinalize::variant_index(end);
+
+                            if start <= end {
+                                (end - start, Some(end - start))
+                            } else {
+                                (0, None)
+                            }
+                        }
+
+                        #[inline]
+                        fn forward_checked(start: Self, count: usize) -> Option<Self> {
+                            Ordinalize::variant_index(&start)
+                                .checked_add(count)
+                                .and_then(<Self as Ordinalize>::from_variant_index)
+                        }
+
+                        #[inline]
+                        fn backward_checked(start: Self, count: usize) -> Option<Self> {
+                            Ordinalize::variant_index(&start)
+                                .checked_sub(count)
+                                .and_then(<Self as Ordinalize>::from_variant_index)
+                        }
+                    }
+                });
+            }
         }
     }
 
@@ -804,5 +1599,208 @@ pub fn ordinalize_derive(input: TokenStream) -> TokenStream {
         });
     }
 
+    if let Some(ord_mode) = ord_mode {
+        expanded.extend(core_impls::impl_ord(
+            &ast,
+            &ord_mode,
+            &variant_type,
+            &values,
+            &variant_idents,
+        ));
+    }
+
+    if enable_hash {
+        expanded.extend(core_impls::impl_hash(&ast, &variant_type, &values, &variant_idents));
+    }
+
+    if let Some(default_variant) = default_variant {
+        expanded.extend(core_impls::impl_default(&ast, &default_variant));
+    }
+
+    if let Some(protobuf) = protobuf {
+        match protobuf::impl_protobuf(
+            &ast,
+            &crate_path,
+            &protobuf,
+            &variant_type,
+            &values,
+            &variant_idents,
+        ) {
+            Ok(token_stream) => expanded.extend(token_stream),
+            Err(error) => return error.into_compile_error().into(),
+        }
+    }
+
+    #[cfg(feature = "rand")]
+    if enable_rand {
+        match rand::impl_distribution(&ast, &crate_path, &variant_idents, &variant_attributes) {
+            Ok(token_stream) => expanded.extend(token_stream),
+            Err(error) => return error.into_compile_error().into(),
+        }
+    }
+
+    #[cfg(feature = "arbitrary")]
+    if enable_arbitrary {
+        expanded.extend(arbitrary::impl_arbitrary(&ast, &crate_path, &variant_idents));
+    }
+
+    #[cfg(any(feature = "borsh", feature = "bincode"))]
+    {
+        let encoding =
+            encoding::Encoding::new(&encode_mode, &variant_type, &values, &variant_idents);
+
+        #[cfg(feature = "borsh")]
+        if enable_borsh {
+            expanded.extend(borsh::impl_borsh(&ast, &crate_path, &encoding));
+        }
+
+        #[cfg(feature = "bincode")]
+        if enable_bincode {
+            expanded.extend(bincode::impl_bincode(
+                &ast,
+                &crate_path,
+                &encode_mode,
+                &encoding,
+                variant_count,
+            ));
+        }
+    }
+
+    #[cfg(feature = "rkyv")]
+    if enable_rkyv {
+        expanded.extend(rkyv::impl_rkyv(&ast, &crate_path, &variant_type));
+    }
+
+    #[cfg(feature = "modular-bitfield")]
+    if enable_modular_bitfield {
+        expanded.extend(modular_bitfield::impl_specifier(&ast, &crate_path));
+    }
+
+    #[cfg(feature = "rusqlite")]
+    if let Some(sql_mode) = &sql_mode {
+        expanded.extend(sql::impl_rusqlite(
+            &ast,
+            &crate_path,
+            sql_mode,
+            &variant_type,
+            &values,
+            &variant_idents,
+        ));
+    }
+
+    #[cfg(feature = "diesel")]
+    if let Some(sql_mode) = &sql_mode {
+        expanded.extend(sql::impl_diesel(
+            &ast,
+            &crate_path,
+            sql_mode,
+            &variant_type,
+            &values,
+            &variant_idents,
+        ));
+    }
+
+    #[cfg(feature = "schemars")]
+    if let Some(schema_mode) = &schema_mode {
+        expanded.extend(schema::impl_schemars(
+            &ast,
+            &crate_path,
+            schema_mode,
+            &values,
+            &variant_idents,
+        ));
+    }
+
+    #[cfg(feature = "utoipa")]
+    if let Some(schema_mode) = &schema_mode {
+        expanded.extend(schema::impl_utoipa(
+            &ast,
+            &crate_path,
+            schema_mode,
+            &values,
+            &variant_idents,
+        ));
+    }
+
+    #[cfg(feature = "valuable")]
+    if enable_valuable {
+        expanded.extend(valuable::impl_valuable(
+            &ast,
+            &crate_path,
+            &variant_type,
+            &values,
+            &variant_idents,
+        ));
+    }
+
+    #[cfg(feature = "defmt")]
+    if let Some(defmt_mode) = &defmt_mode {
+        expanded.extend(defmt::impl_format(
+            &ast,
+            &crate_path,
+            defmt_mode,
+            &variant_type,
+            &values,
+            &variant_idents,
+        ));
+    }
+
+    #[cfg(feature = "clap")]
+    if let Some(clap_options) = clap_options {
+        match clap::impl_value_enum(
+            &ast,
+            &crate_path,
+            &clap_options,
+            &values,
+            &variant_idents,
+            &variant_attributes,
+        ) {
+            Ok(token_stream) => expanded.extend(token_stream),
+            Err(error) => return error.into_compile_error().into(),
+        }
+    }
+
+    if let Some(remote) = remote {
+        let messages = variant_idents.iter().map(|variant_ident| {
+            format!(
+                "the discriminant of `{name}::{variant_ident}` does not match the one of the \
+                 remote enum"
+            )
+        });
+
+        expanded.extend(quote! {
+            const _: () = {
+                #(
+                    ::core::assert!(
+                        #remote::#variant_idents as i128 == #name::#variant_idents as i128,
+                        #messages
+                    );
+                )*
+            };
+
+            impl #impl_generics ::core::convert::From<#name #ty_generics> for #remote #where_clause {
+                #[inline]
+                fn from(value: #name #ty_generics) -> Self {
+                    match value {
+                        #(
+                            #name::#variant_idents => #remote::#variant_idents,
+                        )*
+                    }
+                }
+            }
+
+            impl #impl_generics ::core::convert::From<#remote> for #name #ty_generics #where_clause {
+                #[inline]
+                fn from(value: #remote) -> Self {
+                    match value {
+                        #(
+                            #remote::#variant_idents => #name::#variant_idents,
+                        )*
+                    }
+                }
+            }
+        });
+    }
+
     expanded.into()
 }
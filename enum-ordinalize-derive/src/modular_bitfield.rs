@@ -0,0 +1,36 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{DeriveInput, Path};
+
+pub(crate) fn impl_specifier(ast: &DeriveInput, crate_path: &Path) -> TokenStream {
+    let name = &ast.ident;
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+
+    let modular_bitfield = quote!(#crate_path::__private::modular_bitfield);
+
+    quote! {
+        impl #impl_generics #modular_bitfield::Specifier for #name #ty_generics #where_clause {
+            const BITS: usize = <Self as #crate_path::Ordinalize>::BITS as usize;
+
+            type Bytes = <#crate_path::__private::ByteCount<{ #crate_path::__private::byte_count(<#name #ty_generics as #crate_path::Ordinalize>::BITS) }> as #crate_path::__private::SpecifierBytes>::Bytes;
+
+            type InOut = Self;
+
+            #[inline]
+            fn into_bytes(input: Self::InOut) -> ::core::result::Result<Self::Bytes, #modular_bitfield::error::OutOfBounds> {
+                let bits = <Self as #crate_path::Ordinalize>::into_bits(&input);
+
+                <Self::Bytes as ::core::convert::TryFrom<u64>>::try_from(bits)
+                    .map_err(|_| #modular_bitfield::error::OutOfBounds)
+            }
+
+            #[inline]
+            fn from_bytes(bytes: Self::Bytes) -> ::core::result::Result<Self::InOut, #modular_bitfield::error::InvalidBitPattern<Self::Bytes>> {
+                <u64 as ::core::convert::TryFrom<Self::Bytes>>::try_from(bytes)
+                    .ok()
+                    .and_then(<Self as #crate_path::Ordinalize>::from_bits)
+                    .ok_or_else(|| #modular_bitfield::error::InvalidBitPattern::new(bytes))
+            }
+        }
+    }
+}
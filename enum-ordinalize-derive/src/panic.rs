@@ -61,6 +61,188 @@ pub(crate) fn bool_attribute_usage(name: &Ident, span: Span) -> syn::Error {
     )
 }
 
+#[inline]
+pub(crate) fn int_attribute_usage(name: &Ident, span: Span) -> syn::Error {
+    syn::Error::new(
+        span,
+        format!("the `{name}` attribute should be a name-value pair. The value type is integer"),
+    )
+}
+
+#[inline]
+pub(crate) fn string_attribute_usage(name: &Ident, span: Span) -> syn::Error {
+    syn::Error::new(
+        span,
+        format!("the `{name}` attribute should be a name-value pair. The value type is string"),
+    )
+}
+
+#[inline]
+pub(crate) fn path_attribute_usage(name: &Ident, span: Span) -> syn::Error {
+    syn::Error::new(span, format!("the `{name}` attribute should be a path without a value"))
+}
+
+#[inline]
+pub(crate) fn crate_attribute_usage(span: Span) -> syn::Error {
+    syn::Error::new(
+        span,
+        "the `crate` attribute should be a name-value pair. The value should be a path or a \
+         string of a path",
+    )
+}
+
+#[inline]
+pub(crate) fn ord_attribute_usage(span: Span) -> syn::Error {
+    syn::Error::new(
+        span,
+        "the `ord` attribute should be a name-value pair. The value should be `\"value\"` or \
+         `\"declaration\"`",
+    )
+}
+
+#[inline]
+pub(crate) fn bits_attribute_usage(span: Span) -> syn::Error {
+    syn::Error::new(
+        span,
+        "the `bits` attribute should be a name-value pair. The value should be `\"value\"` or \
+         `\"index\"`",
+    )
+}
+
+#[inline]
+pub(crate) fn encode_attribute_usage(span: Span) -> syn::Error {
+    syn::Error::new(
+        span,
+        "the `encode` attribute should be a name-value pair. The value should be `\"value\"` or \
+         `\"index\"`",
+    )
+}
+
+#[inline]
+pub(crate) fn protobuf_attribute_usage(span: Span) -> syn::Error {
+    syn::Error::new(
+        span,
+        "the `protobuf` attribute should be a path, or a name-value pair whose value is \
+         `\"proto2\"` or `\"proto3\"`",
+    )
+}
+
+#[inline]
+pub(crate) fn duplicate_str_name(str_name: &str, span: Span) -> syn::Error {
+    syn::Error::new(
+        span,
+        format!("the protobuf name `{str_name}` is used by more than one variant"),
+    )
+}
+
+#[inline]
+pub(crate) fn sql_attribute_usage(span: Span) -> syn::Error {
+    syn::Error::new(
+        span,
+        "the `sql` attribute should be a name-value pair. The value should be `\"value\"` or \
+         `\"name\"`",
+    )
+}
+
+#[inline]
+pub(crate) fn schema_attribute_usage(span: Span) -> syn::Error {
+    syn::Error::new(
+        span,
+        "the `schema` attribute should be a name-value pair. The value should be `\"value\"` or \
+         `\"name\"`",
+    )
+}
+
+#[inline]
+pub(crate) fn defmt_attribute_usage(span: Span) -> syn::Error {
+    syn::Error::new(
+        span,
+        "the `defmt` attribute should be a name-value pair. The value should be `\"name\"` or \
+         `\"ordinal\"`",
+    )
+}
+
+#[inline]
+pub(crate) fn clap_attribute_usage(span: Span) -> syn::Error {
+    syn::Error::new(
+        span,
+        "the `clap` attribute should be a path, or a list containing `rename_all = \"...\"` and \
+         `ordinal`",
+    )
+}
+
+#[inline]
+pub(crate) fn clap_variant_attribute_usage(span: Span) -> syn::Error {
+    syn::Error::new(
+        span,
+        "the `clap` attribute on a variant should be a list containing `name = \"...\"` and `hide`",
+    )
+}
+
+#[inline]
+pub(crate) fn rename_all_usage(span: Span) -> syn::Error {
+    syn::Error::new(
+        span,
+        "the `rename_all` attribute should be a name-value pair. The value should be \
+         `\"verbatim\"`, `\"lower\"`, `\"UPPER\"`, `\"PascalCase\"`, `\"camelCase\"`, \
+         `\"snake_case\"`, `\"SCREAMING_SNAKE_CASE\"` or `\"kebab-case\"`",
+    )
+}
+
+#[cfg(feature = "clap")]
+#[inline]
+pub(crate) fn duplicate_possible_value(name: &str, span: Span) -> syn::Error {
+    syn::Error::new(span, format!("the possible value `{name}` is used by more than one variant"))
+}
+
+#[cfg(feature = "clap")]
+#[inline]
+pub(crate) fn clap_ordinal_not_literal(span: Span) -> syn::Error {
+    syn::Error::new(
+        span,
+        "the `ordinal` option of the `clap` attribute requires the value of every variant to be \
+         an integer literal",
+    )
+}
+
+#[inline]
+pub(crate) fn default_attribute_usage(span: Span) -> syn::Error {
+    syn::Error::new(
+        span,
+        "the `default` attribute should be a path, or a name-value pair whose value is the \
+         identifier of a variant",
+    )
+}
+
+#[inline]
+pub(crate) fn unknown_variant(name: &Ident, span: Span) -> syn::Error {
+    syn::Error::new(span, format!("there is no variant named `{name}`"))
+}
+
+#[inline]
+pub(crate) fn feature_required(name: &Ident, features: &str, span: Span) -> syn::Error {
+    syn::Error::new(
+        span,
+        format!("the `{name}` attribute needs the {features} feature of `enum-ordinalize`"),
+    )
+}
+
+#[inline]
+pub(crate) fn generics_unsupported(name: &Ident, span: Span) -> syn::Error {
+    syn::Error::new(span, format!("the `{name}` attribute does not support generic enums"))
+}
+
+#[inline]
+pub(crate) fn trait_required(name: &Ident, span: Span) -> syn::Error {
+    syn::Error::new(
+        span,
+        format!(
+            "the `{name}` attribute relies on the `Ordinalize` trait, so it cannot be used with \
+             `impl_trait = false`"
+        ),
+    )
+}
+
 #[inline]
 pub(crate) fn sub_attributes_for_ordinalize(span: Span) -> syn::Error {
     syn::Error::new(
@@ -69,13 +251,56 @@ pub(crate) fn sub_attributes_for_ordinalize(span: Span) -> syn::Error {
             "available sub-attributes for the `ordinalize` attribute:{}",
             DisplayStringSlice(&[
                 "impl_trait",
+                "impl_step",
                 "variant_count",
                 "variants",
                 "values",
                 "ordinal",
                 "from_ordinal_unsafe",
                 "from_ordinal",
+                "crate",
+                "remote",
+                "ord",
+                "hash",
+                "default",
+                "encode",
+                "protobuf",
+                "sql",
+                "schema",
+                "clap",
+                "defmt",
+                "bits",
+                "rand",
+                "arbitrary",
+                "borsh",
+                "bincode",
+                "modular_bitfield",
+                "valuable",
+                "rkyv",
             ])
         ),
     )
 }
+
+#[inline]
+pub(crate) fn sub_attributes_for_ordinalize_variant(span: Span) -> syn::Error {
+    syn::Error::new(
+        span,
+        format!(
+            "available sub-attributes for the `ordinalize` attribute on a variant:{}",
+            DisplayStringSlice(&["weight", "clap"])
+        ),
+    )
+}
+
+#[cfg(feature = "rand")]
+#[inline]
+pub(crate) fn zero_total_weight(span: Span) -> syn::Error {
+    syn::Error::new(span, "the total weight of the variants needs to be greater than zero")
+}
+
+#[cfg(feature = "rand")]
+#[inline]
+pub(crate) fn total_weight_overflow(span: Span) -> syn::Error {
+    syn::Error::new(span, "the total weight of the variants is too large")
+}
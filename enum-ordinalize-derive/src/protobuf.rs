@@ -0,0 +1,156 @@
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{ext::IdentExt, DeriveInput, Ident, Path};
+
+use crate::{
+    case::to_screaming_snake_case, int_wrapper::IntWrapper, panic, variant_type::VariantType,
+};
+
+pub(crate) enum ProtobufSyntax {
+    Proto2,
+    Proto3,
+}
+
+impl ProtobufSyntax {
+    #[inline]
+    pub(crate) fn from_str<S: AsRef<str>>(s: S) -> Option<ProtobufSyntax> {
+        match s.as_ref() {
+            "proto2" => Some(ProtobufSyntax::Proto2),
+            "proto3" => Some(ProtobufSyntax::Proto3),
+            _ => None,
+        }
+    }
+}
+
+pub(crate) fn impl_protobuf(
+    ast: &DeriveInput,
+    crate_path: &Path,
+    syntax: &ProtobufSyntax,
+    variant_type: &VariantType,
+    values: &[IntWrapper],
+    variant_idents: &[Ident],
+) -> syn::Result<TokenStream> {
+    let name = &ast.ident;
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+
+    let str_names: Vec<String> = variant_idents
+        .iter()
+        .map(|ident| to_screaming_snake_case(&ident.unraw().to_string()))
+        .collect();
+
+    for (i, str_name) in str_names.iter().enumerate() {
+        if str_names[..i].contains(str_name) {
+            return Err(panic::duplicate_str_name(str_name, variant_idents[i].span()));
+        }
+    }
+
+    let range_messages = variant_idents.iter().map(|variant_ident| {
+        format!("the value of `{name}::{variant_ident}` is out of the range of `i32`")
+    });
+
+    // widen the values without changing their signs, or `u128::MAX` would become `-1`
+    let in_range = if variant_type.is_signed() {
+        quote!(value as i128 >= i32::MIN as i128 && value as i128 <= i32::MAX as i128)
+    } else {
+        quote!(value as u128 <= i32::MAX as u128)
+    };
+
+    let zero_assertion = match syntax {
+        ProtobufSyntax::Proto2 => quote!(),
+        ProtobufSyntax::Proto3 => {
+            let first_value = &values[0];
+            let message = format!(
+                "the first variant of `{name}` should have the value `0` in proto3, so that it is \
+                 the default value"
+            );
+
+            quote! {
+                ::core::assert!((#first_value) as i128 == 0, #message);
+            }
+        },
+    };
+
+    Ok(quote! {
+        const _: () = {
+            #(
+                ::core::assert!(
+                    {
+                        let value: #variant_type = #values;
+
+                        #in_range
+                    },
+                    #range_messages
+                );
+            )*
+
+            #zero_assertion
+        };
+
+        impl #impl_generics #name #ty_generics #where_clause {
+            /// Return `true` if `value` is the value of a variant.
+            #[inline]
+            pub fn is_valid(value: i32) -> bool {
+                Self::from_i32(value).is_some()
+            }
+
+            /// Obtain a variant based on an `i32` number.
+            #[inline]
+            pub fn from_i32(value: i32) -> Option<Self> {
+                if false {
+                    unreachable!()
+                } #( else if value == (#values) as i32 {
+                    Some(Self::#variant_idents)
+                } )* else {
+                    None
+                }
+            }
+
+            /// Retrieve the name of this variant in `SCREAMING_SNAKE_CASE`, as used in protobuf definitions.
+            #[inline]
+            pub fn as_str_name(&self) -> &'static str {
+                match self {
+                    #(
+                        Self::#variant_idents => #str_names,
+                    )*
+                }
+            }
+
+            /// Obtain a variant based on its name in `SCREAMING_SNAKE_CASE`, as used in protobuf definitions.
+            #[inline]
+            pub fn from_str_name(value: &str) -> Option<Self> {
+                match value {
+                    #(
+                        #str_names => Some(Self::#variant_idents),
+                    )*
+                    _ => None,
+                }
+            }
+        }
+
+        impl #impl_generics ::core::convert::TryFrom<i32> for #name #ty_generics #where_clause {
+            type Error = #crate_path::UnknownEnumValue;
+
+            #[inline]
+            fn try_from(value: i32) -> ::core::result::Result<Self, Self::Error> {
+                Self::from_i32(value).ok_or(#crate_path::UnknownEnumValue(value))
+            }
+        }
+
+        impl #impl_generics ::core::convert::From<#name #ty_generics> for i32 #where_clause {
+            #[inline]
+            fn from(value: #name #ty_generics) -> i32 {
+                match value {
+                    #(
+                        #name::#variant_idents => (#values) as i32,
+                    )*
+                }
+            }
+        }
+    })
+}
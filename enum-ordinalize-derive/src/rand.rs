@@ -0,0 +1,70 @@
+use alloc::vec::Vec;
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{DeriveInput, Ident, Path};
+
+use crate::{panic, variant_attributes::VariantAttributes};
+
+pub(crate) fn impl_distribution(
+    ast: &DeriveInput,
+    crate_path: &Path,
+    variant_idents: &[Ident],
+    variant_attributes: &[VariantAttributes],
+) -> syn::Result<TokenStream> {
+    let name = &ast.ident;
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+
+    let rand = quote!(#crate_path::__private::rand);
+
+    let variant_count = variant_idents.len();
+    let variant_indexes = 0..variant_count;
+
+    let sample = if variant_attributes.iter().any(|attributes| attributes.weight.is_some()) {
+        let mut cumulative_weights: Vec<u64> = Vec::with_capacity(variant_count);
+        let mut total_weight = 0u64;
+
+        for attributes in variant_attributes {
+            total_weight = total_weight
+                .checked_add(attributes.weight.unwrap_or(1))
+                .ok_or_else(|| panic::total_weight_overflow(name.span()))?;
+
+            cumulative_weights.push(total_weight);
+        }
+
+        if total_weight == 0 {
+            return Err(panic::zero_total_weight(name.span()));
+        }
+
+        quote! {
+            const CUMULATIVE_WEIGHTS: [u64; #variant_count] = [#( #cumulative_weights, )*];
+
+            let n = #rand::Rng::gen_range(rng, 0..#total_weight);
+
+            match CUMULATIVE_WEIGHTS.partition_point(|weight| *weight <= n) {
+                #(
+                    #variant_indexes => #name::#variant_idents,
+                )*
+                _ => unreachable!(),
+            }
+        }
+    } else {
+        quote! {
+            match #rand::Rng::gen_range(rng, 0..#variant_count) {
+                #(
+                    #variant_indexes => #name::#variant_idents,
+                )*
+                _ => unreachable!(),
+            }
+        }
+    };
+
+    Ok(quote! {
+        impl #impl_generics #rand::distributions::Distribution<#name #ty_generics> for #rand::distributions::Standard #where_clause {
+            #[inline]
+            fn sample<R: #rand::Rng + ?Sized>(&self, rng: &mut R) -> #name #ty_generics {
+                #sample
+            }
+        }
+    })
+}
@@ -0,0 +1,68 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{parse_quote, DeriveInput, Path};
+
+use crate::variant_type::VariantType;
+
+pub(crate) fn impl_rkyv(
+    ast: &DeriveInput,
+    crate_path: &Path,
+    variant_type: &VariantType,
+) -> TokenStream {
+    let name = &ast.ident;
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+
+    let rkyv = quote!(#crate_path::__private::rkyv);
+
+    let mut serialize_generics = ast.generics.clone();
+    serialize_generics.params.push(parse_quote!(S: #rkyv::rancor::Fallible + ?Sized));
+
+    // only the values of `isize` and `usize` can be out of the range of their archived form
+    let serialize = match variant_type {
+        VariantType::ISize | VariantType::USize => {
+            serialize_generics
+                .make_where_clause()
+                .predicates
+                .push(parse_quote!(S::Error: #rkyv::rancor::Source));
+
+            let (serialize_impl_generics, _, serialize_where_clause) =
+                serialize_generics.split_for_impl();
+
+            quote! {
+                impl #serialize_impl_generics #rkyv::Serialize<S> for #name #ty_generics #serialize_where_clause {
+                #[inline]
+                fn serialize(&self, _serializer: &mut S) -> ::core::result::Result<Self::Resolver, S::Error> {
+                        #crate_path::rkyv::ArchivedOrdinal::serialize_from::<S>(self)
+                    }
+                }
+            }
+        },
+        _ => {
+            let (serialize_impl_generics, _, serialize_where_clause) =
+                serialize_generics.split_for_impl();
+
+            quote! {
+                impl #serialize_impl_generics #rkyv::Serialize<S> for #name #ty_generics #serialize_where_clause {
+                    #[inline]
+                    fn serialize(&self, _serializer: &mut S) -> ::core::result::Result<Self::Resolver, S::Error> {
+                        Ok(())
+                    }
+                }
+            }
+        },
+    };
+
+    quote! {
+        impl #impl_generics #rkyv::Archive for #name #ty_generics #where_clause {
+            type Archived = #crate_path::rkyv::ArchivedOrdinal<Self>;
+            type Resolver = ();
+
+            #[inline]
+            fn resolve(&self, _resolver: Self::Resolver, out: #rkyv::Place<Self::Archived>) {
+                #crate_path::rkyv::ArchivedOrdinal::resolve_from(self, out)
+            }
+        }
+
+        #serialize
+    }
+}
@@ -0,0 +1,136 @@
+#![cfg_attr(not(any(feature = "schemars", feature = "utoipa")), allow(dead_code, unused_imports))]
+
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{ext::IdentExt, DeriveInput, Ident, Path};
+
+use crate::int_wrapper::IntWrapper;
+
+/// How the variants are described in JSON Schema and OpenAPI documents.
+pub(crate) enum SchemaMode {
+    Value,
+    Name,
+}
+
+impl SchemaMode {
+    #[inline]
+    pub(crate) fn from_str<S: AsRef<str>>(s: S) -> Option<SchemaMode> {
+        match s.as_ref() {
+            "value" => Some(SchemaMode::Value),
+            "name" => Some(SchemaMode::Name),
+            _ => None,
+        }
+    }
+}
+
+fn variant_names(variant_idents: &[Ident]) -> Vec<String> {
+    variant_idents.iter().map(|ident| ident.unraw().to_string()).collect()
+}
+
+#[cfg(feature = "schemars")]
+pub(crate) fn impl_schemars(
+    ast: &DeriveInput,
+    crate_path: &Path,
+    schema_mode: &SchemaMode,
+    values: &[IntWrapper],
+    variant_idents: &[Ident],
+) -> TokenStream {
+    let name = &ast.ident;
+    let name_string = name.unraw().to_string();
+
+    let schemars = quote!(#crate_path::__private::schemars);
+
+    let variant_names = variant_names(variant_idents);
+
+    let schema = match schema_mode {
+        SchemaMode::Value => quote! {
+            #schemars::json_schema!({
+                "type": "integer",
+                "enum": [#(#values),*],
+                "x-enum-varnames": [#(#variant_names),*],
+            })
+        },
+        SchemaMode::Name => quote! {
+            #schemars::json_schema!({
+                "type": "string",
+                "enum": [#(#variant_names),*],
+            })
+        },
+    };
+
+    quote! {
+        impl #schemars::JsonSchema for #name {
+            #[inline]
+            fn schema_name() -> #crate_path::__private::Cow<'static, str> {
+                #crate_path::__private::Cow::Borrowed(#name_string)
+            }
+
+            #[inline]
+            fn schema_id() -> #crate_path::__private::Cow<'static, str> {
+                #crate_path::__private::Cow::Borrowed(::core::concat!(::core::module_path!(), "::", #name_string))
+            }
+
+            #[inline]
+            fn json_schema(_generator: &mut #schemars::SchemaGenerator) -> #schemars::Schema {
+                #schema
+            }
+        }
+    }
+}
+
+#[cfg(feature = "utoipa")]
+pub(crate) fn impl_utoipa(
+    ast: &DeriveInput,
+    crate_path: &Path,
+    schema_mode: &SchemaMode,
+    values: &[IntWrapper],
+    variant_idents: &[Ident],
+) -> TokenStream {
+    let name = &ast.ident;
+    let name_string = name.unraw().to_string();
+
+    let utoipa = quote!(#crate_path::__private::utoipa);
+    let serde_json = quote!(#crate_path::__private::serde_json);
+    let schema = quote!(#utoipa::openapi::schema);
+
+    let variant_names = variant_names(variant_idents);
+
+    let object = match schema_mode {
+        SchemaMode::Value => quote! {
+            #schema::ObjectBuilder::new()
+                .schema_type(#schema::Type::Integer)
+                .enum_values(Some([#(#serde_json::json!(#values)),*]))
+                .extensions(Some(
+                    #utoipa::openapi::extensions::ExtensionsBuilder::new()
+                        .add("x-enum-varnames", #serde_json::json!([#(#variant_names),*]))
+                        .build(),
+                ))
+        },
+        SchemaMode::Name => quote! {
+            #schema::ObjectBuilder::new()
+                .schema_type(#schema::Type::String)
+                .enum_values(Some([#(#variant_names),*]))
+        },
+    };
+
+    quote! {
+        impl #utoipa::PartialSchema for #name {
+            #[inline]
+            fn schema() -> #utoipa::openapi::RefOr<#schema::Schema> {
+                #utoipa::openapi::RefOr::T(#schema::Schema::Object(#object.build()))
+            }
+        }
+
+        impl #utoipa::ToSchema for #name {
+            #[inline]
+            fn name() -> #crate_path::__private::Cow<'static, str> {
+                #crate_path::__private::Cow::Borrowed(#name_string)
+            }
+        }
+    }
+}
@@ -0,0 +1,337 @@
+#![cfg_attr(not(any(feature = "rusqlite", feature = "diesel")), allow(dead_code, unused_imports))]
+
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{ext::IdentExt, DeriveInput, Ident, Path};
+
+use crate::{int_wrapper::IntWrapper, variant_type::VariantType};
+
+/// How the variants are stored in SQL columns.
+pub(crate) enum SqlMode {
+    Value,
+    Name,
+}
+
+impl SqlMode {
+    #[inline]
+    pub(crate) fn from_str<S: AsRef<str>>(s: S) -> Option<SqlMode> {
+        match s.as_ref() {
+            "value" => Some(SqlMode::Value),
+            "name" => Some(SqlMode::Name),
+            _ => None,
+        }
+    }
+}
+
+fn variant_names(variant_idents: &[Ident]) -> Vec<String> {
+    variant_idents.iter().map(|ident| ident.unraw().to_string()).collect()
+}
+
+#[cfg(feature = "rusqlite")]
+pub(crate) fn impl_rusqlite(
+    ast: &DeriveInput,
+    crate_path: &Path,
+    sql_mode: &SqlMode,
+    variant_type: &VariantType,
+    values: &[IntWrapper],
+    variant_idents: &[Ident],
+) -> TokenStream {
+    let name = &ast.ident;
+
+    let rusqlite = quote!(#crate_path::__private::rusqlite);
+
+    match sql_mode {
+        SqlMode::Value => {
+            quote! {
+                impl #rusqlite::types::ToSql for #name {
+                    #[inline]
+                    fn to_sql(&self) -> #rusqlite::Result<#rusqlite::types::ToSqlOutput<'_>> {
+                        let ordinal: #variant_type = match self {
+                            #(
+                                Self::#variant_idents => #values,
+                            )*
+                        };
+
+                        let value = <i64 as ::core::convert::TryFrom<#variant_type>>::try_from(ordinal)
+                            .map_err(|error| #rusqlite::Error::ToSqlConversionFailure(::std::boxed::Box::new(error)))?;
+
+                        Ok(#rusqlite::types::ToSqlOutput::from(value))
+                    }
+                }
+
+                impl #rusqlite::types::FromSql for #name {
+                    #[inline]
+                    fn column_result(value: #rusqlite::types::ValueRef<'_>) -> #rusqlite::types::FromSqlResult<Self> {
+                        let value = value.as_i64()?;
+
+                        let number = <#variant_type as ::core::convert::TryFrom<i64>>::try_from(value)
+                            .map_err(|_| #rusqlite::types::FromSqlError::OutOfRange(value))?;
+
+                        <Self as #crate_path::Ordinalize>::from_ordinal(number).ok_or_else(|| {
+                            #rusqlite::types::FromSqlError::Other(::std::boxed::Box::new(
+                                #crate_path::UnknownOrdinalError::<Self>::new(number),
+                            ))
+                        })
+                    }
+                }
+            }
+        },
+        SqlMode::Name => {
+            let variant_names = variant_names(variant_idents);
+
+            quote! {
+                impl #rusqlite::types::ToSql for #name {
+                    #[inline]
+                    fn to_sql(&self) -> #rusqlite::Result<#rusqlite::types::ToSqlOutput<'_>> {
+                        let name: &'static str = match self {
+                            #(
+                                Self::#variant_idents => #variant_names,
+                            )*
+                        };
+
+                        Ok(#rusqlite::types::ToSqlOutput::from(name))
+                    }
+                }
+
+                impl #rusqlite::types::FromSql for #name {
+                    #[inline]
+                    fn column_result(value: #rusqlite::types::ValueRef<'_>) -> #rusqlite::types::FromSqlResult<Self> {
+                        match value.as_str()? {
+                            #(
+                                #variant_names => Ok(Self::#variant_idents),
+                            )*
+                            name => Err(#rusqlite::types::FromSqlError::Other(::std::boxed::Box::new(
+                                #crate_path::UnknownVariantNameError::<Self>::new(name),
+                            ))),
+                        }
+                    }
+                }
+            }
+        },
+    }
+}
+
+#[cfg(feature = "diesel")]
+pub(crate) fn impl_diesel(
+    ast: &DeriveInput,
+    crate_path: &Path,
+    sql_mode: &SqlMode,
+    variant_type: &VariantType,
+    values: &[IntWrapper],
+    variant_idents: &[Ident],
+) -> TokenStream {
+    let name = &ast.ident;
+
+    let diesel = quote!(#crate_path::__private::diesel);
+
+    // `AsExpression` is implemented by converting a variant to its value or name, which implement it
+    let (sql_type, rust_type, to_sql, from_sql, expression_type, expression, assertion) =
+        match sql_mode {
+            SqlMode::Value => {
+                let (sql_type, int_type) = match variant_type {
+                    VariantType::I8 | VariantType::U8 | VariantType::I16 => {
+                        (quote!(#diesel::sql_types::SmallInt), quote!(i16))
+                    },
+                    VariantType::U16 | VariantType::I32 => {
+                        (quote!(#diesel::sql_types::Integer), quote!(i32))
+                    },
+                    _ => (quote!(#diesel::sql_types::BigInt), quote!(i64)),
+                };
+
+                // widen the values without changing their signs to check them against the integer type
+                let in_range = if variant_type.is_signed() {
+                    quote!(value as i128 >= #int_type::MIN as i128 && value as i128 <= #int_type::MAX as i128)
+                } else {
+                    quote!(value as u128 <= #int_type::MAX as u128)
+                };
+
+                let range_messages = variant_idents.iter().map(|variant_ident| {
+                    format!(
+                        "the value of `{name}::{variant_ident}` is out of the range of \
+                         `{int_type}`, so it cannot be stored in the column"
+                    )
+                });
+
+                let assertion = quote! {
+                    const _: () = {
+                        #(
+                            ::core::assert!(
+                                {
+                                    let value: #variant_type = #values;
+
+                                    #in_range
+                                },
+                                #range_messages
+                            );
+                        )*
+                    };
+                };
+
+                let expression = quote! {
+                    match self {
+                        #(
+                            #name::#variant_idents => (#values) as #int_type,
+                        )*
+                    }
+                };
+
+                // a reference to a constant lives long enough to be bound
+                let to_sql = quote! {
+                    match self {
+                        #(
+                            Self::#variant_idents => {
+                                const VALUE: #int_type = (#values) as #int_type;
+
+                                <#int_type as #diesel::serialize::ToSql<#sql_type, DB>>::to_sql(&VALUE, out)
+                            },
+                        )*
+                    }
+                };
+
+                let from_sql = quote! {
+                    let value = <#int_type as #diesel::deserialize::FromSql<#sql_type, DB>>::from_sql(bytes)?;
+
+                    let number = <#variant_type as ::core::convert::TryFrom<#int_type>>::try_from(value)?;
+
+                    <Self as #crate_path::Ordinalize>::from_ordinal(number).ok_or_else(|| {
+                        #crate_path::UnknownOrdinalError::<Self>::new(number).into()
+                    })
+                };
+
+                (sql_type, int_type.clone(), to_sql, from_sql, int_type, expression, assertion)
+            },
+            SqlMode::Name => {
+                let sql_type = quote!(#diesel::sql_types::Text);
+
+                let variant_names = variant_names(variant_idents);
+
+                let expression = quote! {
+                    match self {
+                        #(
+                            #name::#variant_idents => #variant_names,
+                        )*
+                    }
+                };
+
+                let to_sql = quote! {
+                    let name: &'static str = match self {
+                        #(
+                            Self::#variant_idents => #variant_names,
+                        )*
+                    };
+
+                    <str as #diesel::serialize::ToSql<#sql_type, DB>>::to_sql(name, out)
+                };
+
+                let from_sql = quote! {
+                    let name = <::std::string::String as #diesel::deserialize::FromSql<#sql_type, DB>>::from_sql(bytes)?;
+
+                    match name.as_str() {
+                        #(
+                            #variant_names => Ok(Self::#variant_idents),
+                        )*
+                        name => Err(::std::boxed::Box::new(
+                            #crate_path::UnknownVariantNameError::<Self>::new(name),
+                        )),
+                    }
+                };
+
+                (
+                    sql_type,
+                    quote!(::std::string::String),
+                    to_sql,
+                    from_sql,
+                    quote!(&'static str),
+                    expression,
+                    quote!(),
+                )
+            },
+        };
+
+    let to_sql_bound = match sql_mode {
+        SqlMode::Value => quote!(#rust_type: #diesel::serialize::ToSql<#sql_type, DB>),
+        SqlMode::Name => quote!(str: #diesel::serialize::ToSql<#sql_type, DB>),
+    };
+
+    let nullable_sql_type = quote!(#diesel::sql_types::Nullable<#sql_type>);
+
+    let as_expression = [
+        (quote!(), quote!(#name), &sql_type),
+        (quote!(), quote!(#name), &nullable_sql_type),
+        (quote!(<'expr>), quote!(&'expr #name), &sql_type),
+        (quote!(<'expr>), quote!(&'expr #name), &nullable_sql_type),
+    ]
+    .into_iter()
+    .map(|(impl_generics, self_type, sql_type)| {
+        quote! {
+            impl #impl_generics #diesel::expression::AsExpression<#sql_type> for #self_type {
+                type Expression = <#expression_type as #diesel::expression::AsExpression<#sql_type>>::Expression;
+
+                #[inline]
+                fn as_expression(self) -> Self::Expression {
+                    let value: #expression_type = #expression;
+
+                    <#expression_type as #diesel::expression::AsExpression<#sql_type>>::as_expression(value)
+                }
+            }
+        }
+    });
+
+    quote! {
+        #assertion
+
+        #(#as_expression)*
+
+        impl<DB> #diesel::serialize::ToSql<#sql_type, DB> for #name
+        where
+            DB: #diesel::backend::Backend,
+            #to_sql_bound,
+        {
+            #[inline]
+            fn to_sql<'b>(&'b self, out: &mut #diesel::serialize::Output<'b, '_, DB>) -> #diesel::serialize::Result {
+                #to_sql
+            }
+        }
+
+        impl<DB> #diesel::serialize::ToSql<#nullable_sql_type, DB> for #name
+        where
+            DB: #diesel::backend::Backend,
+            Self: #diesel::serialize::ToSql<#sql_type, DB>,
+        {
+            #[inline]
+            fn to_sql<'b>(&'b self, out: &mut #diesel::serialize::Output<'b, '_, DB>) -> #diesel::serialize::Result {
+                <Self as #diesel::serialize::ToSql<#sql_type, DB>>::to_sql(self, out)
+            }
+        }
+
+        impl<DB> #diesel::deserialize::FromSql<#sql_type, DB> for #name
+        where
+            DB: #diesel::backend::Backend,
+            #rust_type: #diesel::deserialize::FromSql<#sql_type, DB>,
+        {
+            #[inline]
+            fn from_sql(bytes: DB::RawValue<'_>) -> #diesel::deserialize::Result<Self> {
+                #from_sql
+            }
+        }
+
+        impl<DB, ST> #diesel::deserialize::Queryable<ST, DB> for #name
+        where
+            DB: #diesel::backend::Backend,
+            ST: #diesel::sql_types::SingleValue,
+            Self: #diesel::deserialize::FromSql<ST, DB>,
+        {
+            type Row = Self;
+
+            #[inline]
+            fn build(row: Self) -> #diesel::deserialize::Result<Self> {
+                Ok(row)
+            }
+        }
+    }
+}
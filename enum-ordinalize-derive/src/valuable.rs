@@ -0,0 +1,73 @@
+use alloc::{string::ToString, vec::Vec};
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{ext::IdentExt, DeriveInput, Ident, Path};
+
+use crate::{int_wrapper::IntWrapper, variant_type::VariantType};
+
+/// Every variant is described as a variant with one unnamed field, which is its value.
+pub(crate) fn impl_valuable(
+    ast: &DeriveInput,
+    crate_path: &Path,
+    variant_type: &VariantType,
+    values: &[IntWrapper],
+    variant_idents: &[Ident],
+) -> TokenStream {
+    let name = &ast.ident;
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+    let name_string = name.unraw().to_string();
+
+    let valuable = quote!(#crate_path::__private::valuable);
+
+    let variant_names: Vec<_> =
+        variant_idents.iter().map(|ident| ident.unraw().to_string()).collect();
+    let variant_indexes = 0..variant_idents.len();
+
+    // the definitions of the variants are kept in a scope of their own, out of the namespace of the enum
+    quote! {
+        const _: () = {
+            static VARIANTS: &[#valuable::VariantDef<'static>] = &[
+                #(
+                    #valuable::VariantDef::new(#variant_names, #valuable::Fields::Unnamed(1)),
+                )*
+            ];
+
+            impl #impl_generics #valuable::Valuable for #name #ty_generics #where_clause {
+                #[inline]
+                fn as_value(&self) -> #valuable::Value<'_> {
+                    #valuable::Value::Enumerable(self)
+                }
+
+                #[inline]
+                fn visit(&self, visitor: &mut dyn #valuable::Visit) {
+                    let ordinal: #variant_type = match self {
+                        #(
+                            Self::#variant_idents => #values,
+                        )*
+                    };
+
+                    visitor.visit_unnamed_fields(&[#valuable::Valuable::as_value(&ordinal)]);
+                }
+            }
+
+            impl #impl_generics #valuable::Enumerable for #name #ty_generics #where_clause {
+                #[inline]
+                fn definition(&self) -> #valuable::EnumDef<'_> {
+                    #valuable::EnumDef::new_static(#name_string, VARIANTS)
+                }
+
+                #[inline]
+                fn variant(&self) -> #valuable::Variant<'_> {
+                    let index = match self {
+                        #(
+                            Self::#variant_idents => #variant_indexes,
+                        )*
+                    };
+
+                    #valuable::Variant::Static(&VARIANTS[index])
+                }
+            }
+        };
+    }
+}
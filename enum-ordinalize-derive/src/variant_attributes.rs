@@ -0,0 +1,138 @@
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+
+use syn::{punctuated::Punctuated, spanned::Spanned, Attribute, Expr, Lit, Meta, Token};
+
+use crate::panic;
+
+#[derive(Default)]
+pub(crate) struct VariantAttributes {
+    pub(crate) weight:    Option<u64>,
+    /// The first paragraph of the doc comment.
+    pub(crate) help:      Option<String>,
+    pub(crate) clap_name: Option<String>,
+    pub(crate) clap_hide: bool,
+}
+
+impl VariantAttributes {
+    pub(crate) fn parse(attrs: &[Attribute]) -> syn::Result<Self> {
+        let mut variant_attributes = Self::default();
+
+        let mut help_lines: Vec<String> = Vec::new();
+        let mut help_ended = false;
+
+        for attr in attrs {
+            if attr.path().is_ident("doc") {
+                if let Meta::NameValue(meta) = &attr.meta {
+                    if let Expr::Lit(lit) = &meta.value {
+                        if let Lit::Str(value) = &lit.lit {
+                            let line = value.value();
+                            let line = line.trim();
+
+                            if line.is_empty() {
+                                help_ended |= !help_lines.is_empty();
+                            } else if !help_ended {
+                                help_lines.push(line.to_string());
+                            }
+                        }
+                    }
+                }
+
+                continue;
+            }
+
+            if !attr.path().is_ident("ordinalize") {
+                continue;
+            }
+
+            let list = match &attr.meta {
+                Meta::List(list) => list,
+                _ => {
+                    return Err(panic::sub_attributes_for_ordinalize_variant(attr.path().span()));
+                },
+            };
+
+            let result = list.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)?;
+
+            for meta in result {
+                let ident = match meta.path().get_ident() {
+                    Some(ident) => ident,
+                    None => {
+                        return Err(panic::sub_attributes_for_ordinalize_variant(
+                            meta.path().span(),
+                        ))
+                    },
+                };
+
+                let name = ident.to_string();
+
+                let missing_features = match name.as_str() {
+                    "weight" if !cfg!(feature = "rand") => Some("`rand`"),
+                    "clap" if !cfg!(feature = "clap") => Some("`clap`"),
+                    _ => None,
+                };
+
+                if let Some(features) = missing_features {
+                    return Err(panic::feature_required(ident, features, ident.span()));
+                }
+
+                match name.as_str() {
+                    "weight" => {
+                        if let Meta::NameValue(meta) = &meta {
+                            if let Expr::Lit(lit) = &meta.value {
+                                if let Lit::Int(value) = &lit.lit {
+                                    variant_attributes.weight = Some(value.base10_parse()?);
+
+                                    continue;
+                                }
+                            }
+                        }
+
+                        return Err(panic::int_attribute_usage(ident, ident.span()));
+                    },
+                    "clap" => {
+                        if let Meta::List(list) = &meta {
+                            let result = list
+                                .parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)?;
+
+                            for meta in result {
+                                match &meta {
+                                    Meta::Path(path) if path.is_ident("hide") => {
+                                        variant_attributes.clap_hide = true;
+
+                                        continue;
+                                    },
+                                    Meta::NameValue(meta) if meta.path.is_ident("name") => {
+                                        if let Expr::Lit(lit) = &meta.value {
+                                            if let Lit::Str(value) = &lit.lit {
+                                                variant_attributes.clap_name = Some(value.value());
+
+                                                continue;
+                                            }
+                                        }
+                                    },
+                                    _ => (),
+                                }
+
+                                return Err(panic::clap_variant_attribute_usage(ident.span()));
+                            }
+
+                            continue;
+                        }
+
+                        return Err(panic::clap_variant_attribute_usage(ident.span()));
+                    },
+                    _ => return Err(panic::sub_attributes_for_ordinalize_variant(ident.span())),
+                }
+            }
+        }
+
+        if !help_lines.is_empty() {
+            variant_attributes.help = Some(help_lines.join(" "));
+        }
+
+        Ok(variant_attributes)
+    }
+}
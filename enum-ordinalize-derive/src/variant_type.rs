@@ -40,6 +40,20 @@ impl VariantType {
         }
     }
 
+    #[inline]
+    pub(crate) fn is_signed(&self) -> bool {
+        matches!(
+            self,
+            VariantType::ISize
+                | VariantType::I8
+                | VariantType::I16
+                | VariantType::I32
+                | VariantType::I64
+                | VariantType::I128
+                | VariantType::NonDetermined
+        )
+    }
+
     #[inline]
     pub(crate) fn as_str(&self) -> &'static str {
         match self {
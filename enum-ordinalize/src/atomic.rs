@@ -0,0 +1,269 @@
+use core::{
+    fmt::{self, Debug, Formatter},
+    marker::PhantomData,
+    sync::atomic::Ordering,
+};
+
+use crate::{OrdinalInt, Ordinalize};
+
+mod private {
+    pub trait Sealed {}
+}
+
+/// This trait is implemented for the integer types which have a matching atomic type in `core::sync::atomic` on the target, so that [`AtomicEnum`] can be used for the ordinalized enums whose `VariantType` is one of them.
+///
+/// It cannot be implemented outside this crate.
+pub trait AtomicOrdinalInt: OrdinalInt + private::Sealed {
+    /// The atomic type of this integer type, such as `AtomicU8` for `u8`.
+    type Atomic: Send + Sync;
+
+    #[doc(hidden)]
+    fn new_atomic(value: Self) -> Self::Atomic;
+
+    #[doc(hidden)]
+    fn into_inner(atomic: Self::Atomic) -> Self;
+
+    #[doc(hidden)]
+    fn load(atomic: &Self::Atomic, order: Ordering) -> Self;
+
+    #[doc(hidden)]
+    fn store(atomic: &Self::Atomic, value: Self, order: Ordering);
+
+    #[doc(hidden)]
+    fn swap(atomic: &Self::Atomic, value: Self, order: Ordering) -> Self;
+
+    #[doc(hidden)]
+    fn compare_exchange(
+        atomic: &Self::Atomic,
+        current: Self,
+        new: Self,
+        success: Ordering,
+        failure: Ordering,
+    ) -> Result<Self, Self>;
+
+    #[doc(hidden)]
+    fn fetch_update<F: FnMut(Self) -> Option<Self>>(
+        atomic: &Self::Atomic,
+        set_order: Ordering,
+        fetch_order: Ordering,
+        f: F,
+    ) -> Result<Self, Self>;
+}
+
+macro_rules! impl_atomic_ordinal_int {
+    ($($size:literal => $($t:ty => $atomic:ident),*);* $(;)*) => {
+        $($(
+            #[cfg(target_has_atomic = $size)]
+            impl private::Sealed for $t {}
+
+            #[cfg(target_has_atomic = $size)]
+            impl AtomicOrdinalInt for $t {
+                type Atomic = core::sync::atomic::$atomic;
+
+                #[inline]
+                fn new_atomic(value: Self) -> Self::Atomic {
+                    Self::Atomic::new(value)
+                }
+
+                #[inline]
+                fn into_inner(atomic: Self::Atomic) -> Self {
+                    atomic.into_inner()
+                }
+
+                #[inline]
+                fn load(atomic: &Self::Atomic, order: Ordering) -> Self {
+                    atomic.load(order)
+                }
+
+                #[inline]
+                fn store(atomic: &Self::Atomic, value: Self, order: Ordering) {
+                    atomic.store(value, order)
+                }
+
+                #[inline]
+                fn swap(atomic: &Self::Atomic, value: Self, order: Ordering) -> Self {
+                    atomic.swap(value, order)
+                }
+
+                #[inline]
+                fn compare_exchange(
+                    atomic: &Self::Atomic,
+                    current: Self,
+                    new: Self,
+                    success: Ordering,
+                    failure: Ordering,
+                ) -> Result<Self, Self> {
+                    atomic.compare_exchange(current, new, success, failure)
+                }
+
+                #[inline]
+                fn fetch_update<F: FnMut(Self) -> Option<Self>>(
+                    atomic: &Self::Atomic,
+                    set_order: Ordering,
+                    fetch_order: Ordering,
+                    f: F,
+                ) -> Result<Self, Self> {
+                    atomic.fetch_update(set_order, fetch_order, f)
+                }
+            }
+        )*)*
+    };
+}
+
+impl_atomic_ordinal_int! {
+    "8" => i8 => AtomicI8, u8 => AtomicU8;
+    "16" => i16 => AtomicI16, u16 => AtomicU16;
+    "32" => i32 => AtomicI32, u32 => AtomicU32;
+    "64" => i64 => AtomicI64, u64 => AtomicU64;
+    "ptr" => isize => AtomicIsize, usize => AtomicUsize;
+}
+
+/// A variant of an ordinalized enum which can be shared between threads, stored as its value in the atomic integer type matching `VariantType`.
+///
+/// Only the values of variants are ever stored, so converting them back with `from_ordinal` always succeeds for a correct `Ordinalize` implementation.
+///
+/// ```rust
+/// # #[cfg(feature = "derive")]
+/// # {
+/// use std::sync::atomic::Ordering;
+///
+/// use enum_ordinalize::{AtomicEnum, Ordinalize};
+///
+/// #[derive(Debug, PartialEq, Eq, Ordinalize)]
+/// #[repr(u8)]
+/// enum State {
+///     Starting,
+///     Running,
+///     Stopped,
+/// }
+///
+/// let state = AtomicEnum::new(State::Starting);
+///
+/// assert_eq!(
+///     Ok(State::Starting),
+///     state.compare_exchange(
+///         State::Starting,
+///         State::Running,
+///         Ordering::AcqRel,
+///         Ordering::Acquire
+///     )
+/// );
+/// assert_eq!(State::Running, state.swap(State::Stopped, Ordering::AcqRel));
+/// assert_eq!(State::Stopped, state.load(Ordering::Acquire));
+/// # }
+/// ```
+pub struct AtomicEnum<E: Ordinalize>
+where
+    E::VariantType: AtomicOrdinalInt, {
+    atomic:  <E::VariantType as AtomicOrdinalInt>::Atomic,
+    // the enum is never stored, only its ordinal
+    _marker: PhantomData<fn() -> E>,
+}
+
+impl<E: Ordinalize> AtomicEnum<E>
+where
+    E::VariantType: AtomicOrdinalInt,
+{
+    /// Create an atomic variant.
+    #[inline]
+    pub fn new(value: E) -> Self {
+        Self {
+            atomic: E::VariantType::new_atomic(value.ordinal()), _marker: PhantomData
+        }
+    }
+
+    /// Consume the atomic variant and return the contained variant.
+    #[inline]
+    pub fn into_inner(self) -> E {
+        Self::variant(E::VariantType::into_inner(self.atomic))
+    }
+
+    /// Load the variant.
+    #[inline]
+    pub fn load(&self, order: Ordering) -> E {
+        Self::variant(E::VariantType::load(&self.atomic, order))
+    }
+
+    /// Store a variant.
+    #[inline]
+    pub fn store(&self, value: E, order: Ordering) {
+        E::VariantType::store(&self.atomic, value.ordinal(), order)
+    }
+
+    /// Store a variant and return the previous one.
+    #[inline]
+    pub fn swap(&self, value: E, order: Ordering) -> E {
+        Self::variant(E::VariantType::swap(&self.atomic, value.ordinal(), order))
+    }
+
+    /// Store `new` if the current variant is `current`. The previous variant is returned in `Ok` if it was replaced, or in `Err` if it was not.
+    #[inline]
+    pub fn compare_exchange(
+        &self,
+        current: E,
+        new: E,
+        success: Ordering,
+        failure: Ordering,
+    ) -> Result<E, E> {
+        E::VariantType::compare_exchange(
+            &self.atomic,
+            current.ordinal(),
+            new.ordinal(),
+            success,
+            failure,
+        )
+        .map(Self::variant)
+        .map_err(Self::variant)
+    }
+
+    /// Fetch the variant and apply `f` to it until `f` returns `None` or its result is stored. The previous variant is returned in `Ok` if it was replaced, or in `Err` if `f` returned `None`.
+    #[inline]
+    pub fn fetch_update<F: FnMut(E) -> Option<E>>(
+        &self,
+        set_order: Ordering,
+        fetch_order: Ordering,
+        mut f: F,
+    ) -> Result<E, E> {
+        E::VariantType::fetch_update(&self.atomic, set_order, fetch_order, |ordinal| {
+            f(Self::variant(ordinal)).map(|value| value.ordinal())
+        })
+        .map(Self::variant)
+        .map_err(Self::variant)
+    }
+
+    #[inline]
+    fn variant(ordinal: E::VariantType) -> E {
+        // the atomic integer only ever holds the ordinals of variants, unless `ordinal` and `from_ordinal` disagree
+        E::from_ordinal(ordinal).expect("the ordinal of a variant should correspond to the variant")
+    }
+}
+
+impl<E: Ordinalize> From<E> for AtomicEnum<E>
+where
+    E::VariantType: AtomicOrdinalInt,
+{
+    #[inline]
+    fn from(value: E) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<E: Ordinalize + Default> Default for AtomicEnum<E>
+where
+    E::VariantType: AtomicOrdinalInt,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new(E::default())
+    }
+}
+
+impl<E: Ordinalize + Debug> Debug for AtomicEnum<E>
+where
+    E::VariantType: AtomicOrdinalInt,
+{
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Debug::fmt(&self.load(Ordering::Relaxed), f)
+    }
+}
@@ -0,0 +1,135 @@
+use crate::OrdinalInt;
+
+/// The number of bits needed to encode the indexes of `count` variants, which is at least 1.
+#[inline]
+#[doc(hidden)]
+pub const fn index_bits(count: usize) -> u32 {
+    if count <= 2 {
+        1
+    } else {
+        usize::BITS - (count - 1).leading_zeros()
+    }
+}
+
+/// Whether any of the given values is negative, in which case they are encoded in two's complement.
+#[doc(hidden)]
+pub const fn has_negative(values: &[i128]) -> bool {
+    let mut i = 0;
+
+    while i < values.len() {
+        if values[i] < 0 {
+            return true;
+        }
+
+        i += 1;
+    }
+
+    false
+}
+
+/// The number of bits needed to encode the given values, in two's complement if any of them is negative, which is at least 1.
+#[doc(hidden)]
+pub const fn signed_bits(values: &[i128]) -> u32 {
+    let negative = has_negative(values);
+
+    let mut bits = 1;
+    let mut i = 0;
+
+    while i < values.len() {
+        let value = values[i];
+
+        let value_bits = if negative {
+            // the leading bits which are the same as the sign bit are redundant, except for the sign bit itself
+            i128::BITS - (value ^ (value >> (i128::BITS - 1))).leading_zeros() + 1
+        } else {
+            i128::BITS - value.leading_zeros()
+        };
+
+        if value_bits > bits {
+            bits = value_bits;
+        }
+
+        i += 1;
+    }
+
+    bits
+}
+
+/// The number of bits needed to encode the given values, which is at least 1.
+#[doc(hidden)]
+pub const fn unsigned_bits(values: &[u128]) -> u32 {
+    let mut bits = 1;
+    let mut i = 0;
+
+    while i < values.len() {
+        let value_bits = u128::BITS - values[i].leading_zeros();
+
+        if value_bits > bits {
+            bits = value_bits;
+        }
+
+        i += 1;
+    }
+
+    bits
+}
+
+/// Obtain a value from its lowest `width` bits, which are sign-extended only if `negative` is `true`, as `signed_bits` encodes them.
+#[inline]
+#[doc(hidden)]
+pub fn value_from_bits<T: OrdinalInt>(bits: u64, width: u32, negative: bool) -> Option<T> {
+    if negative {
+        T::from_bits(bits, width)
+    } else if bits.checked_shr(width).unwrap_or(0) != 0 {
+        None
+    } else {
+        T::try_from_i128(i128::from(bits))
+    }
+}
+
+/// The number of bytes of the smallest unsigned integer type which can hold `bits` bits, up to 64.
+#[cfg(feature = "modular-bitfield")]
+#[inline]
+#[doc(hidden)]
+pub const fn byte_count(bits: u32) -> usize {
+    if bits <= 8 {
+        1
+    } else if bits <= 16 {
+        2
+    } else if bits <= 32 {
+        4
+    } else {
+        8
+    }
+}
+
+/// Select the `Bytes` type of `modular_bitfield::Specifier` by the number of bytes from `byte_count`.
+#[cfg(feature = "modular-bitfield")]
+#[doc(hidden)]
+pub struct ByteCount<const N: usize>;
+
+#[cfg(feature = "modular-bitfield")]
+#[doc(hidden)]
+pub trait SpecifierBytes {
+    type Bytes;
+}
+
+#[cfg(feature = "modular-bitfield")]
+impl SpecifierBytes for ByteCount<1> {
+    type Bytes = u8;
+}
+
+#[cfg(feature = "modular-bitfield")]
+impl SpecifierBytes for ByteCount<2> {
+    type Bytes = u16;
+}
+
+#[cfg(feature = "modular-bitfield")]
+impl SpecifierBytes for ByteCount<4> {
+    type Bytes = u32;
+}
+
+#[cfg(feature = "modular-bitfield")]
+impl SpecifierBytes for ByteCount<8> {
+    type Bytes = u64;
+}
@@ -0,0 +1,382 @@
+use alloc::{boxed::Box, vec};
+#[cfg(target_has_atomic = "64")]
+use core::sync::atomic::{AtomicU64, Ordering};
+use core::{
+    fmt::{self, Debug, Display, Formatter},
+    iter::FusedIterator,
+    marker::PhantomData,
+};
+
+use crate::{Ordinalize, VariantNames};
+
+/// A counter for each variant of an ordinalized enum, indexed by its position in `VARIANTS`.
+///
+/// ```rust
+/// # #[cfg(feature = "derive")]
+/// # {
+/// use enum_ordinalize::{EnumCounter, Ordinalize};
+///
+/// #[derive(Debug, PartialEq, Eq, Ordinalize)]
+/// enum Status {
+///     Ok,
+///     NotFound,
+///     Error,
+/// }
+///
+/// let mut counter = EnumCounter::new();
+///
+/// counter.increment(&Status::Ok);
+/// counter.increment(&Status::Ok);
+/// counter.add(&Status::Error, 3);
+///
+/// assert_eq!(2, counter.get(&Status::Ok));
+/// assert_eq!(0, counter.get(&Status::NotFound));
+/// assert_eq!(Some((Status::Error, 3)), counter.most_common());
+/// assert_eq!("Ok       2\nNotFound 0\nError    3", counter.to_string());
+/// # }
+/// ```
+pub struct EnumCounter<E: Ordinalize> {
+    counts:  Box<[u64]>,
+    _marker: PhantomData<fn() -> E>,
+}
+
+impl<E: Ordinalize> EnumCounter<E> {
+    /// Create a counter whose counts are all zero.
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            counts: vec![0; E::VARIANT_COUNT].into_boxed_slice(), _marker: PhantomData
+        }
+    }
+
+    /// Add one to the count of a variant.
+    #[inline]
+    pub fn increment(&mut self, value: &E) {
+        self.add(value, 1);
+    }
+
+    /// Add `count` to the count of a variant. The count wraps around on overflow, as the one of `AtomicEnumCounter` does.
+    #[inline]
+    pub fn add(&mut self, value: &E, count: u64) {
+        let total = &mut self.counts[value.variant_index()];
+
+        *total = total.wrapping_add(count);
+    }
+
+    /// Return the count of a variant.
+    #[inline]
+    pub fn get(&self, value: &E) -> u64 {
+        self.counts[value.variant_index()]
+    }
+
+    /// Return the sum of the counts of all variants, which wraps around on overflow.
+    #[inline]
+    pub fn total(&self) -> u64 {
+        self.counts.iter().fold(0, |total, count| total.wrapping_add(*count))
+    }
+
+    /// Add the counts of another counter to this counter. The counts wrap around on overflow.
+    #[inline]
+    pub fn merge(&mut self, other: &Self) {
+        for (count, other_count) in self.counts.iter_mut().zip(other.counts.iter()) {
+            *count = count.wrapping_add(*other_count);
+        }
+    }
+
+    /// Return the variant with the largest count, which is the first one in declaration order if there is a tie, and its count. `None` is returned if all counts are zero.
+    #[inline]
+    pub fn most_common(&self) -> Option<(E, u64)> {
+        let mut most_common: Option<(usize, u64)> = None;
+
+        for (index, count) in self.counts.iter().copied().enumerate() {
+            if count > most_common.map_or(0, |(_, most_count)| most_count) {
+                most_common = Some((index, count));
+            }
+        }
+
+        most_common.and_then(|(index, count)| Some((E::from_variant_index(index)?, count)))
+    }
+
+    /// Set all counts to zero.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.counts.fill(0);
+    }
+
+    /// Iterate over the variants and their counts, in declaration order.
+    #[inline]
+    pub fn iter(&self) -> EnumCounterIter<'_, E> {
+        EnumCounterIter {
+            counts:  &self.counts,
+            front:   0,
+            back:    self.counts.len(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<E: Ordinalize> Default for EnumCounter<E> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<E: Ordinalize> Clone for EnumCounter<E> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            counts: self.counts.clone(), _marker: PhantomData
+        }
+    }
+}
+
+impl<E: Ordinalize> PartialEq for EnumCounter<E> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.counts == other.counts
+    }
+}
+
+impl<E: Ordinalize> Eq for EnumCounter<E> {}
+
+impl<E: Ordinalize + Debug> Debug for EnumCounter<E> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+/// Render a table with a line for each variant, which has its name padded to the longest name and its count aligned to the right.
+impl<E: VariantNames> Display for EnumCounter<E> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let name_width =
+            E::VARIANT_NAMES.iter().map(|name| name.chars().count()).max().unwrap_or(0);
+        let count_width = self
+            .counts
+            .iter()
+            .map(|count| count.checked_ilog10().map_or(1, |digits| digits as usize + 1))
+            .max()
+            .unwrap_or(0);
+
+        for (index, (name, count)) in E::VARIANT_NAMES.iter().zip(self.counts.iter()).enumerate() {
+            if index > 0 {
+                f.write_str("\n")?;
+            }
+
+            write!(f, "{name:<name_width$} {count:>count_width$}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<'a, E: Ordinalize> Extend<&'a E> for EnumCounter<E> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = &'a E>>(&mut self, iter: I) {
+        for value in iter {
+            self.increment(value);
+        }
+    }
+}
+
+impl<E: Ordinalize> Extend<E> for EnumCounter<E> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = E>>(&mut self, iter: I) {
+        for value in iter {
+            self.increment(&value);
+        }
+    }
+}
+
+impl<E: Ordinalize> FromIterator<E> for EnumCounter<E> {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = E>>(iter: I) -> Self {
+        let mut counter = Self::new();
+
+        counter.extend(iter);
+
+        counter
+    }
+}
+
+impl<'a, E: Ordinalize> IntoIterator for &'a EnumCounter<E> {
+    type IntoIter = EnumCounterIter<'a, E>;
+    type Item = (E, u64);
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// An iterator over the variants and their counts of an [`EnumCounter`].
+pub struct EnumCounterIter<'a, E: Ordinalize> {
+    counts:  &'a [u64],
+    front:   usize,
+    back:    usize,
+    _marker: PhantomData<fn() -> E>,
+}
+
+impl<'a, E: Ordinalize> Clone for EnumCounterIter<'a, E> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            counts: self.counts, front: self.front, back: self.back, _marker: PhantomData
+        }
+    }
+}
+
+impl<'a, E: Ordinalize> Debug for EnumCounterIter<'a, E> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("EnumCounterIter")
+            .field("front", &self.front)
+            .field("back", &self.back)
+            .finish()
+    }
+}
+
+impl<'a, E: Ordinalize> Iterator for EnumCounterIter<'a, E> {
+    type Item = (E, u64);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front < self.back {
+            let index = self.front;
+
+            self.front += 1;
+
+            Some((E::from_variant_index(index)?, self.counts[index]))
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.back - self.front;
+
+        (len, Some(len))
+    }
+}
+
+impl<'a, E: Ordinalize> DoubleEndedIterator for EnumCounterIter<'a, E> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front < self.back {
+            self.back -= 1;
+
+            Some((E::from_variant_index(self.back)?, self.counts[self.back]))
+        } else {
+            None
+        }
+    }
+}
+
+impl<'a, E: Ordinalize> ExactSizeIterator for EnumCounterIter<'a, E> {}
+
+impl<'a, E: Ordinalize> FusedIterator for EnumCounterIter<'a, E> {}
+
+/// A counter for each variant of an ordinalized enum which can be shared between threads, indexed by its position in `VARIANTS`.
+///
+/// The counts are updated with relaxed atomic operations. Use [`AtomicEnumCounter::snapshot`] to read all of them as an [`EnumCounter`].
+///
+/// ```rust
+/// # #[cfg(feature = "derive")]
+/// # {
+/// use enum_ordinalize::{AtomicEnumCounter, Ordinalize};
+///
+/// #[derive(Debug, PartialEq, Eq, Ordinalize)]
+/// enum Status {
+///     Ok,
+///     NotFound,
+///     Error,
+/// }
+///
+/// let counter = AtomicEnumCounter::new();
+///
+/// counter.increment(&Status::NotFound);
+/// counter.add(&Status::Ok, 5);
+///
+/// assert_eq!(1, counter.get(&Status::NotFound));
+/// assert_eq!(Some((Status::Ok, 5)), counter.snapshot().most_common());
+/// # }
+/// ```
+#[cfg(target_has_atomic = "64")]
+pub struct AtomicEnumCounter<E: Ordinalize> {
+    counts:  Box<[AtomicU64]>,
+    _marker: PhantomData<fn() -> E>,
+}
+
+#[cfg(target_has_atomic = "64")]
+impl<E: Ordinalize> AtomicEnumCounter<E> {
+    /// Create a counter whose counts are all zero.
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            counts:  (0..E::VARIANT_COUNT).map(|_| AtomicU64::new(0)).collect(),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Add one to the count of a variant.
+    #[inline]
+    pub fn increment(&self, value: &E) {
+        self.add(value, 1);
+    }
+
+    /// Add `count` to the count of a variant. The count wraps around on overflow.
+    #[inline]
+    pub fn add(&self, value: &E, count: u64) {
+        self.counts[value.variant_index()].fetch_add(count, Ordering::Relaxed);
+    }
+
+    /// Return the count of a variant.
+    #[inline]
+    pub fn get(&self, value: &E) -> u64 {
+        self.counts[value.variant_index()].load(Ordering::Relaxed)
+    }
+
+    /// Add the counts of a counter to this counter. The counts wrap around on overflow.
+    #[inline]
+    pub fn merge(&self, other: &EnumCounter<E>) {
+        for (count, other_count) in self.counts.iter().zip(other.counts.iter()) {
+            count.fetch_add(*other_count, Ordering::Relaxed);
+        }
+    }
+
+    /// Read all counts. The counts are read one by one, so the updates made meanwhile may be partially included.
+    #[inline]
+    pub fn snapshot(&self) -> EnumCounter<E> {
+        EnumCounter {
+            counts:  self.counts.iter().map(|count| count.load(Ordering::Relaxed)).collect(),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Set all counts to zero and return the counts before.
+    #[inline]
+    pub fn take(&self) -> EnumCounter<E> {
+        EnumCounter {
+            counts:  self.counts.iter().map(|count| count.swap(0, Ordering::Relaxed)).collect(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+#[cfg(target_has_atomic = "64")]
+impl<E: Ordinalize> Default for AtomicEnumCounter<E> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(target_has_atomic = "64")]
+impl<E: Ordinalize + Debug> Debug for AtomicEnumCounter<E> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Debug::fmt(&self.snapshot(), f)
+    }
+}
@@ -0,0 +1,125 @@
+use core::ops::{Add, AddAssign, Deref, Sub, SubAssign};
+
+use crate::Ordinalize;
+
+/// A wrapper of an ordinalized enum whose arithmetic wraps around the variants in declaration order.
+///
+/// Adding `n` to a variant moves it `n` positions forward in `VARIANTS`, and moving past the last variant continues from the first one. The values of the variants are not involved.
+///
+/// ```rust
+/// # #[cfg(feature = "derive")]
+/// # {
+/// use enum_ordinalize::{Cyclic, Ordinalize};
+///
+/// #[derive(Debug, PartialEq, Eq, Ordinalize)]
+/// enum Weekday {
+///     Sun,
+///     Mon,
+///     Tue,
+///     Wed,
+///     Thu,
+///     Fri,
+///     Sat,
+/// }
+///
+/// const SUNDAY: Cyclic<Weekday> = Cyclic::new(Weekday::Sun);
+///
+/// assert_eq!(Weekday::Wed, *(SUNDAY + 3));
+/// assert_eq!(Weekday::Fri, *(SUNDAY - 2));
+/// assert_eq!(Weekday::Mon, *(SUNDAY + 15));
+///
+/// assert_eq!(3, Cyclic::distance(&Weekday::Sun, &Weekday::Wed));
+/// assert_eq!(4, Cyclic::distance(&Weekday::Wed, &Weekday::Sun));
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(transparent)]
+pub struct Cyclic<E>(E);
+
+impl<E> Cyclic<E> {
+    /// Wrap a variant.
+    #[inline]
+    pub const fn new(variant: E) -> Self {
+        Self(variant)
+    }
+
+    /// Unwrap the variant.
+    #[inline]
+    pub fn into_inner(self) -> E {
+        self.0
+    }
+}
+
+impl<E: Ordinalize> Cyclic<E> {
+    /// Count the steps needed to move forward from `from` to `to`, wrapping around the variants.
+    #[inline]
+    pub fn distance(from: &E, to: &E) -> usize {
+        let from = from.variant_index();
+        let to = to.variant_index();
+
+        if from <= to {
+            to - from
+        } else {
+            E::VARIANT_COUNT - from + to
+        }
+    }
+
+    #[inline]
+    fn offset(&self, n: isize) -> E {
+        let count = E::VARIANT_COUNT as isize;
+        let index = self.0.variant_index() as isize;
+
+        let index = (index + n.rem_euclid(count)) % count;
+
+        E::from_variant_index(index as usize).unwrap()
+    }
+}
+
+impl<E> Deref for Cyclic<E> {
+    type Target = E;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<E> From<E> for Cyclic<E> {
+    #[inline]
+    fn from(variant: E) -> Self {
+        Self(variant)
+    }
+}
+
+impl<E: Ordinalize> Add<isize> for Cyclic<E> {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: isize) -> Self::Output {
+        Self(self.offset(rhs))
+    }
+}
+
+impl<E: Ordinalize> Sub<isize> for Cyclic<E> {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: isize) -> Self::Output {
+        // negate in the modulus to avoid overflowing on `isize::MIN`
+        Self(self.offset(-(rhs % E::VARIANT_COUNT as isize)))
+    }
+}
+
+impl<E: Ordinalize> AddAssign<isize> for Cyclic<E> {
+    #[inline]
+    fn add_assign(&mut self, rhs: isize) {
+        self.0 = self.offset(rhs);
+    }
+}
+
+impl<E: Ordinalize> SubAssign<isize> for Cyclic<E> {
+    #[inline]
+    fn sub_assign(&mut self, rhs: isize) {
+        self.0 = self.offset(-(rhs % E::VARIANT_COUNT as isize));
+    }
+}
@@ -0,0 +1,70 @@
+use crate::OrdinalInt;
+
+mod private {
+    pub trait Sealed {}
+}
+
+/// This trait is implemented for the types which describe a byte order. They are used as type parameters to choose how ordinals are read and written.
+///
+/// It cannot be implemented outside this crate.
+pub trait Endianness: private::Sealed {
+    /// Return the memory representation of an integer as a byte array in this byte order.
+    fn to_bytes<T: OrdinalInt>(number: T) -> T::Bytes;
+
+    /// Create an integer from its representation as a byte array in this byte order.
+    fn from_bytes<T: OrdinalInt>(bytes: T::Bytes) -> T;
+}
+
+/// The big-endian byte order, also known as network byte order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BigEndian {}
+
+/// The little-endian byte order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LittleEndian {}
+
+/// The byte order of the target platform.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NativeEndian {}
+
+impl private::Sealed for BigEndian {}
+
+impl Endianness for BigEndian {
+    #[inline]
+    fn to_bytes<T: OrdinalInt>(number: T) -> T::Bytes {
+        number.to_be_bytes()
+    }
+
+    #[inline]
+    fn from_bytes<T: OrdinalInt>(bytes: T::Bytes) -> T {
+        T::from_be_bytes(bytes)
+    }
+}
+
+impl private::Sealed for LittleEndian {}
+
+impl Endianness for LittleEndian {
+    #[inline]
+    fn to_bytes<T: OrdinalInt>(number: T) -> T::Bytes {
+        number.to_le_bytes()
+    }
+
+    #[inline]
+    fn from_bytes<T: OrdinalInt>(bytes: T::Bytes) -> T {
+        T::from_le_bytes(bytes)
+    }
+}
+
+impl private::Sealed for NativeEndian {}
+
+impl Endianness for NativeEndian {
+    #[inline]
+    fn to_bytes<T: OrdinalInt>(number: T) -> T::Bytes {
+        number.to_ne_bytes()
+    }
+
+    #[inline]
+    fn from_bytes<T: OrdinalInt>(bytes: T::Bytes) -> T {
+        T::from_ne_bytes(bytes)
+    }
+}
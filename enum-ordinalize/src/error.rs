@@ -0,0 +1,132 @@
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+use core::{
+    any::type_name,
+    fmt::{self, Debug, Display, Formatter},
+    marker::PhantomData,
+};
+
+use crate::Ordinalize;
+
+/// An error which indicates that a number is not the value of any variant of `E`.
+pub struct UnknownOrdinalError<E: Ordinalize> {
+    value:   E::VariantType,
+    _marker: PhantomData<fn() -> E>,
+}
+
+impl<E: Ordinalize> UnknownOrdinalError<E> {
+    /// Create an error for a number which does not correspond to any variant.
+    #[inline]
+    pub fn new(value: E::VariantType) -> Self {
+        Self {
+            value,
+            _marker: PhantomData,
+        }
+    }
+
+    /// The number which does not correspond to any variant.
+    #[inline]
+    pub fn value(&self) -> E::VariantType {
+        self.value
+    }
+}
+
+impl<E: Ordinalize> Clone for UnknownOrdinalError<E> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<E: Ordinalize> Copy for UnknownOrdinalError<E> {}
+
+impl<E: Ordinalize> PartialEq for UnknownOrdinalError<E> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl<E: Ordinalize> Eq for UnknownOrdinalError<E> {}
+
+impl<E: Ordinalize> Debug for UnknownOrdinalError<E> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("UnknownOrdinalError").field("value", &self.value).finish()
+    }
+}
+
+impl<E: Ordinalize> Display for UnknownOrdinalError<E> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "`{}` is not the value of any variant of `{}`", self.value, type_name::<E>())
+    }
+}
+
+#[cfg(feature = "std")]
+impl<E: Ordinalize> std::error::Error for UnknownOrdinalError<E> {}
+
+#[cfg(all(feature = "rkyv", not(feature = "std")))]
+impl<E: Ordinalize> core::error::Error for UnknownOrdinalError<E> {}
+
+/// An error which indicates that a string is not the name of any variant of `E`.
+#[cfg(feature = "alloc")]
+pub struct UnknownVariantNameError<E: Ordinalize> {
+    name:    String,
+    _marker: PhantomData<fn() -> E>,
+}
+
+#[cfg(feature = "alloc")]
+impl<E: Ordinalize> UnknownVariantNameError<E> {
+    /// Create an error for a string which does not correspond to any variant.
+    #[inline]
+    pub fn new<S: Into<String>>(name: S) -> Self {
+        Self {
+            name: name.into(), _marker: PhantomData
+        }
+    }
+
+    /// The string which does not correspond to any variant.
+    #[inline]
+    pub fn name(&self) -> &str {
+        self.name.as_str()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<E: Ordinalize> Clone for UnknownVariantNameError<E> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self::new(self.name.clone())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<E: Ordinalize> PartialEq for UnknownVariantNameError<E> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<E: Ordinalize> Eq for UnknownVariantNameError<E> {}
+
+#[cfg(feature = "alloc")]
+impl<E: Ordinalize> Debug for UnknownVariantNameError<E> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("UnknownVariantNameError").field("name", &self.name).finish()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<E: Ordinalize> Display for UnknownVariantNameError<E> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "`{}` is not the name of any variant of `{}`", self.name, type_name::<E>())
+    }
+}
+
+#[cfg(feature = "std")]
+impl<E: Ordinalize> std::error::Error for UnknownVariantNameError<E> {}
@@ -148,6 +148,380 @@ assert_eq!(MyEnum::NegativeNine, unsafe { MyEnum::from_ordinal_unsafe(-9i8) });
 # }
 ```
 
+#### Conversion between Integer Types
+
+The `from_ordinal_any` and `ordinal_as` methods convert ordinals of other integer types without truncation.
+
+```rust
+# #[cfg(all(feature = "derive", feature = "traits"))]
+# {
+use enum_ordinalize::Ordinalize;
+
+#[derive(Debug, PartialEq, Eq, Ordinalize)]
+enum MyEnum {
+    Zero,
+    One,
+    Two,
+}
+
+assert_eq!(Some(MyEnum::One), MyEnum::from_ordinal_any(1u32));
+assert_eq!(None, MyEnum::from_ordinal_any(0x101u32));
+
+assert_eq!(Some(2u64), MyEnum::Two.ordinal_as());
+# }
+```
+
+#### Without Procedural Macros
+
+The `ordinalize!` macro declares an enum and implements the `Ordinalize` trait for it without the `enum-ordinalize-derive` crate, so it is available with `default-features = false, features = ["traits"]`. The `#[repr(type)]` attribute is required.
+
+```rust
+# #[cfg(feature = "traits")]
+# {
+use enum_ordinalize::Ordinalize;
+
+enum_ordinalize::ordinalize! {
+    #[derive(Debug, PartialEq, Eq)]
+    #[repr(u8)]
+    pub enum MyEnum {
+        A = 1,
+        B,
+        C,
+    }
+}
+
+assert_eq!([1u8, 2u8, 3u8], MyEnum::VALUES);
+assert_eq!(Some(MyEnum::B), MyEnum::from_ordinal(2u8));
+# }
+```
+
+#### Remote Enums
+
+An enum defined in another crate cannot implement the `Ordinalize` trait in your crate. Instead, you can declare a mirror enum with the same variants and values and use the `remote` attribute to generate `From` implementations between the two enums. Every variant and its value are checked at compile time.
+
+```rust
+# #[cfg(all(feature = "derive", feature = "traits"))]
+# {
+use enum_ordinalize::Ordinalize;
+
+mod other_crate {
+    #[derive(Debug, PartialEq, Eq)]
+    pub enum Mode {
+        Read = 1,
+        Write = 2,
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Ordinalize)]
+#[ordinalize(remote = "other_crate::Mode")]
+enum Mode {
+    Read = 1,
+    Write = 2,
+}
+
+assert_eq!(2i8, Mode::from(other_crate::Mode::Write).ordinal());
+assert_eq!(Some(other_crate::Mode::Read), Mode::from_ordinal(1i8).map(other_crate::Mode::from));
+# }
+```
+
+#### Ordering, Hashing and Default Values
+
+The `ord`, `hash` and `default` attributes implement some standard traits based on the variants. `ord = "value"` compares the values of the variants and `ord = "declaration"` compares their positions in the declaration. `hash` hashes only the ordinal. `default` returns the first variant, or the variant assigned to it.
+
+```rust
+# #[cfg(all(feature = "derive", feature = "traits"))]
+# {
+use enum_ordinalize::Ordinalize;
+
+#[derive(Debug, PartialEq, Eq, Ordinalize)]
+#[ordinalize(ord = "declaration", hash, default = Low)]
+enum Priority {
+    High = 3,
+    Low = 1,
+    Mid = 2,
+}
+
+assert!(Priority::High < Priority::Low);
+assert_eq!(Priority::Low, Priority::default());
+# }
+```
+
+#### Ranges of Variants
+
+The `range` method iterates over the variants between two bounds in declaration order.
+
+```rust
+# #[cfg(all(feature = "derive", feature = "traits"))]
+# {
+use enum_ordinalize::Ordinalize;
+
+#[derive(Debug, PartialEq, Eq, Ordinalize)]
+enum Level {
+    Trace,
+    Debug,
+    Info,
+    Warn,
+    Error,
+}
+
+assert_eq!(3, Level::Warn.variant_index());
+assert_eq!(Some(Level::Info), Level::from_variant_index(2));
+
+assert_eq!(vec![Level::Warn, Level::Error], Level::range(Level::Warn..).collect::<Vec<_>>());
+assert_eq!(vec![Level::Debug, Level::Info], Level::range(Level::Debug..=Level::Info).collect::<Vec<_>>());
+# }
+```
+
+On the nightly channel, the `impl_step` attribute implements the `core::iter::Step` trait, so that ranges such as `Level::Debug..=Level::Warn` can iterate directly. It needs `#![feature(step_trait)]`, and the enum needs to implement `Clone` and a `PartialOrd` consistent with the declaration order (the derived `PartialOrd` compares discriminants, so they need to be ascending).
+
+```rust,ignore
+#![feature(step_trait)]
+
+use enum_ordinalize::Ordinalize;
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ordinalize)]
+#[ordinalize(impl_step = true)]
+enum Level {
+    Trace,
+    Debug,
+    Info,
+    Warn,
+    Error,
+}
+
+assert_eq!(vec![Level::Debug, Level::Info, Level::Warn], (Level::Debug..=Level::Warn).collect::<Vec<_>>());
+```
+
+#### Cyclic Arithmetic
+
+The `Cyclic` wrapper moves between variants in declaration order and wraps around at both ends.
+
+```rust
+# #[cfg(all(feature = "derive", feature = "traits"))]
+# {
+use enum_ordinalize::{Cyclic, Ordinalize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Ordinalize)]
+enum Weekday {
+    Sun,
+    Mon,
+    Tue,
+    Wed,
+    Thu,
+    Fri,
+    Sat,
+}
+
+let sunday = Cyclic::new(Weekday::Sun);
+
+assert_eq!(Weekday::Wed, *(sunday + 3));
+assert_eq!(Weekday::Sat, *(sunday - 1));
+assert_eq!(6, Cyclic::distance(&Weekday::Mon, &Weekday::Sun));
+# }
+```
+
+#### Byte Encoding
+
+The ordinal of a variant can be converted from and to a byte array whose size is the size of `VariantType`.
+
+```rust
+# #[cfg(all(feature = "derive", feature = "traits"))]
+# {
+use enum_ordinalize::Ordinalize;
+
+#[derive(Debug, PartialEq, Eq, Ordinalize)]
+#[repr(u16)]
+enum Kind {
+    Header = 0x0102,
+    Body = 0x0304,
+}
+
+assert_eq!([0x01, 0x02], Kind::Header.to_be_bytes());
+assert_eq!(Some(Kind::Body), Kind::from_le_bytes([0x04, 0x03]));
+assert_eq!(None, Kind::from_be_bytes([0x04, 0x03]));
+# }
+```
+
+#### Bit Fields
+
+`BITS` is the minimum number of bits to encode every variant, and `into_bits` and `from_bits` convert a variant into and from them. A variant is encoded as its value, in two's complement if any value is negative. With the `bits = "index"` attribute, it is encoded as its index instead, which is also the default if the values need more than 64 bits. With the `bits = "value"` attribute, such values are a compile error.
+
+```rust
+# #[cfg(all(feature = "derive", feature = "traits"))]
+# {
+use enum_ordinalize::Ordinalize;
+
+#[derive(Debug, PartialEq, Eq, Ordinalize)]
+#[repr(u8)]
+enum Mode {
+    Input = 0b00,
+    Output = 0b01,
+    Analog = 0b11,
+}
+
+#[derive(Debug, PartialEq, Eq, Ordinalize)]
+#[ordinalize(bits = "index")]
+#[repr(u16)]
+enum Kind {
+    Header = 0x0102,
+    Body = 0x0304,
+    Trailer = 0x0506,
+}
+
+assert_eq!(2, Mode::BITS);
+assert_eq!(0b11, Mode::Analog.into_bits());
+assert_eq!(None, Mode::from_bits(0b10));
+
+assert_eq!(2, Kind::BITS);
+assert_eq!(Some(Kind::Trailer), Kind::from_bits(2));
+# }
+```
+
+#### Atomic Variants
+
+`AtomicEnum` stores a variant in the atomic integer type matching the type of the values, such as `AtomicU8` for `u8`, so it can be shared between threads without locks.
+
+```rust
+# #[cfg(all(feature = "derive", feature = "traits"))]
+# {
+use core::sync::atomic::Ordering;
+
+use enum_ordinalize::{AtomicEnum, Ordinalize};
+
+#[derive(Debug, PartialEq, Eq, Ordinalize)]
+#[repr(u8)]
+enum State {
+    Starting,
+    Running,
+    Stopping,
+    Stopped,
+}
+
+let state = AtomicEnum::new(State::Starting);
+
+assert_eq!(Ok(State::Starting), state.compare_exchange(State::Starting, State::Running, Ordering::AcqRel, Ordering::Acquire));
+assert_eq!(Err(State::Running), state.compare_exchange(State::Starting, State::Stopped, Ordering::AcqRel, Ordering::Acquire));
+
+assert_eq!(
+    Ok(State::Running),
+    state.fetch_update(Ordering::AcqRel, Ordering::Acquire, |state| match state {
+        State::Running => Some(State::Stopping),
+        _ => None,
+    })
+);
+
+assert_eq!(State::Stopping, state.swap(State::Stopped, Ordering::AcqRel));
+assert_eq!(State::Stopped, state.load(Ordering::Acquire));
+# }
+```
+
+#### Lookup Tables
+
+The `enum_table!` macro declares a table which holds a value for each variant in the order of `VARIANTS`. Its `get` method is a `const fn`. Without the `struct` declaration, the macro is an expression which evaluates to such a table. The entries must list every variant exactly once in declaration order, otherwise the table fails to compile.
+
+```rust
+# #[cfg(all(feature = "derive", feature = "traits"))]
+# {
+use enum_ordinalize::{enum_table, Ordinalize};
+
+#[derive(Debug, PartialEq, Eq, Ordinalize)]
+enum Format {
+    Json,
+    Toml,
+    Yaml,
+}
+
+enum_table! {
+    pub struct MimeTypes: Format => &'static str {
+        Json => "application/json",
+        Toml => "application/toml",
+        Yaml => "application/yaml",
+    }
+}
+
+const JSON: &str = MimeTypes.get(&Format::Json);
+
+assert_eq!("application/json", JSON);
+assert_eq!(["application/json", "application/toml", "application/yaml"], MimeTypes::TABLE);
+# }
+```
+
+#### Variant Names
+
+The `VariantNames` trait is implemented along with the `Ordinalize` trait. It provides the names of the variants.
+
+```rust
+# #[cfg(all(feature = "derive", feature = "traits"))]
+# {
+use enum_ordinalize::{Ordinalize, VariantNames};
+
+#[derive(Debug, PartialEq, Eq, Ordinalize)]
+enum Level {
+    Info = 2,
+    Warn = 1,
+}
+
+assert_eq!(["Info", "Warn"], Level::VARIANT_NAMES);
+assert_eq!("Warn", Level::Warn.variant_name());
+assert_eq!(Some(Level::Info), Level::from_variant_name("Info"));
+# }
+```
+
+#### Renamed Dependency
+
+The generated code refers to this crate as `::enum_ordinalize`. If the dependency is renamed in `Cargo.toml`, such as `eo = { package = "enum-ordinalize", version = "4" }`, set its path with the `crate` attribute.
+
+```rust,ignore
+use eo::Ordinalize;
+
+#[derive(Debug, PartialEq, Eq, Ordinalize)]
+#[ordinalize(crate = eo)]
+enum Level {
+    Info,
+    Warn,
+}
+```
+
+#### Protobuf-compatible Enums
+
+The `protobuf` attribute generates the API of enums generated by [prost](https://crates.io/crates/prost): `is_valid`, `from_i32`, `as_str_name`, `from_str_name`, `TryFrom<i32>` and `From<Enum> for i32`. The names are the identifiers of the variants in `SCREAMING_SNAKE_CASE`. The values of the variants are checked to be in the range of `i32` at compile time. With `protobuf = "proto3"`, the first variant is also required to have the value `0`.
+
+```rust
+# #[cfg(feature = "derive")]
+# {
+use enum_ordinalize::{Ordinalize, UnknownEnumValue};
+
+#[derive(Debug, PartialEq, Eq, Ordinalize)]
+#[ordinalize(protobuf = "proto3")]
+#[repr(i32)]
+enum Status {
+    Unspecified = 0,
+    Ok = 1,
+    NotFound = 5,
+}
+
+assert!(Status::is_valid(5));
+assert_eq!(Ok(Status::Ok), Status::try_from(1));
+assert_eq!(Err(UnknownEnumValue(2)), Status::try_from(2));
+assert_eq!("NOT_FOUND", Status::NotFound.as_str_name());
+assert_eq!(Some(Status::NotFound), Status::from_str_name("NOT_FOUND"));
+# }
+```
+
+A value out of the range of `i32` fails to compile, including the values of `u128` beyond the range of `i128`.
+
+```rust,compile_fail
+use enum_ordinalize::Ordinalize;
+
+#[derive(Ordinalize)]
+#[ordinalize(protobuf)]
+#[repr(u128)]
+enum Status {
+    Unknown = 0,
+    Max = u128::MAX,
+}
+```
+
 #### Implement Functionality for an enum on Itself
 
 For some reason, if you don't want to implement the `Ordinalize` trait for your enum, you can choose to disable the trait implementation and enable the constants/functions one by one. Functions are `const fn`. Names and visibility can also be defined by you.
@@ -189,15 +563,583 @@ assert_eq!(MyEnum::A, unsafe { MyEnum::from_ordinal_unsafe(0i8) });
 assert_eq!(MyEnum::B, unsafe { MyEnum::from_ordinal_unsafe(1i8) });
 # }
 ```
+
+## Optional Features
+
+#### `alloc`
+
+Enable the `alloc` feature to use `PackedVec`, a vector which stores each variant as its index in `VARIANTS` using only the minimum number of bits. Its words can be persisted with `as_raw_words` and restored with `from_raw_words`.
+
+```rust
+# #[cfg(all(feature = "derive", feature = "traits", feature = "alloc"))]
+# {
+use enum_ordinalize::{Ordinalize, PackedVec};
+
+#[derive(Debug, PartialEq, Eq, Ordinalize)]
+enum Tile {
+    Empty,
+    Wall,
+    Water,
+    Grass,
+    Lava,
+}
+
+let mut tiles: PackedVec<Tile> = [Tile::Wall, Tile::Lava].into_iter().collect();
+
+tiles.push(Tile::Grass);
+tiles.set(0, Tile::Water);
+
+assert_eq!(Some(Tile::Water), tiles.get(0));
+assert_eq!(Some(Tile::Grass), tiles.pop());
+assert_eq!(vec![Tile::Water, Tile::Lava], tiles.iter().collect::<Vec<_>>());
+
+let words = tiles.as_raw_words().to_vec();
+
+assert_eq!(Some(tiles), PackedVec::from_raw_words(words, 2));
+# }
+```
+
+`EnumCounter` counts the occurrences of each variant, and `AtomicEnumCounter` does it with atomic counters which can be shared between threads.
+
+```rust
+# #[cfg(all(feature = "derive", feature = "traits", feature = "alloc"))]
+# {
+use enum_ordinalize::{AtomicEnumCounter, EnumCounter, Ordinalize};
+
+#[derive(Debug, PartialEq, Eq, Ordinalize)]
+enum Status {
+    Ok,
+    NotFound,
+    Error,
+}
+
+let mut counter: EnumCounter<Status> = [Status::Ok, Status::Error, Status::Ok].into_iter().collect();
+
+let shared = AtomicEnumCounter::new();
+
+shared.increment(&Status::NotFound);
+shared.add(&Status::Error, 2);
+
+counter.merge(&shared.snapshot());
+
+assert_eq!(3, counter.get(&Status::Error));
+assert_eq!(Some((Status::Error, 3)), counter.most_common());
+assert_eq!(vec![(Status::Ok, 2), (Status::NotFound, 1), (Status::Error, 3)], counter.iter().collect::<Vec<_>>());
+assert_eq!("Ok       2\nNotFound 1\nError    3", counter.to_string());
+# }
+```
+
+`sort_by_variant` and `sort_by_key_variant` sort a slice by variants in declaration order, and `sort_by_variant_value` and `sort_by_key_variant_value` sort it in ascending order of the values. They are stable counting sorts which take `O(n + k)` time and extra space for `k` variants, or `O(n·k)` time for a manual `Ordinalize` implementation which keeps the default `variant_index`, as it searches `VALUES`. `group_by_variant` sorts a slice in the same way and provides the elements of each variant as a subslice.
+
+```rust
+# #[cfg(all(feature = "derive", feature = "traits", feature = "alloc"))]
+# {
+use enum_ordinalize::Ordinalize;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Ordinalize)]
+enum Kind {
+    Click = 2,
+    Scroll = 1,
+}
+
+let mut events = [(Kind::Scroll, 1), (Kind::Click, 2), (Kind::Scroll, 3)];
+
+enum_ordinalize::sort_by_key_variant_value(&mut events, |event| event.0);
+
+assert_eq!([(Kind::Scroll, 1), (Kind::Scroll, 3), (Kind::Click, 2)], events);
+
+let groups = enum_ordinalize::group_by_variant(&mut events, |event| event.0);
+
+assert_eq!([(Kind::Click, 2)], groups.get(&Kind::Click));
+assert_eq!([(Kind::Scroll, 1), (Kind::Scroll, 3)], groups.get(&Kind::Scroll));
+# }
+```
+
+#### `std`
+
+Enable the `std` feature to read variants from an `io::Read` and write them to an `io::Write` in a chosen byte order. An ordinal which does not correspond to any variant results in an error of the `InvalidData` kind.
+
+```rust
+# #[cfg(all(feature = "derive", feature = "std"))]
+# {
+use enum_ordinalize::{BigEndian, Ordinalize};
+
+#[derive(Debug, PartialEq, Eq, Ordinalize)]
+#[repr(u16)]
+enum Kind {
+    Header = 0x0102,
+    Body = 0x0304,
+}
+
+let mut data = Vec::new();
+
+Kind::Body.write_to::<BigEndian, _>(&mut data).unwrap();
+
+assert_eq!(Kind::Body, Kind::read_from::<BigEndian, _>(&mut data.as_slice()).unwrap());
+assert!(Kind::read_from::<BigEndian, _>(&mut [0xFF, 0xFF].as_slice()).is_err());
+# }
+```
+
+#### `nom`
+
+Enable the `nom` feature to parse variants with [`nom`](https://crates.io/crates/nom). `nom::ordinal` parses an ordinal in a chosen byte order, and `nom::name` parses the longest matching variant name.
+
+```rust
+# #[cfg(all(feature = "derive", feature = "nom"))]
+# {
+use enum_ordinalize::{nom::*, BigEndian, Ordinalize};
+use nom::{IResult, Parser};
+
+#[derive(Debug, PartialEq, Eq, Ordinalize)]
+#[repr(u16)]
+enum Kind {
+    Header = 0x0102,
+    HeaderExtension = 0x0103,
+}
+
+let result: IResult<&[u8], Kind> = ordinal::<Kind, BigEndian, _, _>().parse(&[0x01, 0x02]);
+assert_eq!(Ok((&[][..], Kind::Header)), result);
+
+let result: IResult<&str, Kind> = name::<Kind, _, _>().parse("HeaderExtension");
+assert_eq!(Ok(("", Kind::HeaderExtension)), result);
+# }
+```
+
+#### `borsh` and `bincode`
+
+Enable the `borsh` feature or the `bincode` feature to implement `BorshSerialize` and `BorshDeserialize`, or `Encode` and `Decode` of [bincode](https://crates.io/crates/bincode) 2, on the enums with the `borsh` attribute or the `bincode` attribute. A variant is encoded as its value, in the type of the values of the variants. With the `encode = "index"` attribute, a variant is encoded as its index in declaration order instead, using the smallest unsigned integer type which can hold the index. Decoding an unknown value results in an error. The `bincode` feature requires Rust 1.85, as bincode 2 does.
+
+```rust
+# #[cfg(all(feature = "derive", feature = "borsh"))]
+# {
+use enum_ordinalize::Ordinalize;
+
+#[derive(Debug, PartialEq, Eq, Ordinalize)]
+#[ordinalize(borsh)]
+#[repr(u16)]
+enum Kind {
+    Header = 0x0102,
+    Body = 0x0304,
+}
+
+#[derive(Debug, PartialEq, Eq, Ordinalize)]
+#[ordinalize(borsh, encode = "index")]
+#[repr(u16)]
+enum IndexedKind {
+    Header = 0x0102,
+    Body = 0x0304,
+}
+
+assert_eq!(vec![0x04, 0x03], borsh::to_vec(&Kind::Body).unwrap());
+assert_eq!(vec![1], borsh::to_vec(&IndexedKind::Body).unwrap());
+assert!(borsh::from_slice::<Kind>(&[0x00, 0x00]).is_err());
+# }
+```
+
+#### `rkyv`
+
+Enable the `rkyv` feature to implement `Archive` and `Serialize` of [rkyv](https://crates.io/crates/rkyv) 0.8 on the enums with the `rkyv` attribute. It requires Rust 1.81, as rkyv 0.8 does. The archived form of a variant is its archived value, and validating an archive rejects any value which does not correspond to a variant.
+
+```rust
+# #[cfg(all(feature = "derive", feature = "rkyv"))]
+# {
+use enum_ordinalize::Ordinalize;
+use rkyv::{rancor::Error, Archived};
+
+#[derive(Debug, PartialEq, Eq, Ordinalize)]
+#[ordinalize(rkyv)]
+#[repr(u16)]
+enum Kind {
+    Header = 0x0102,
+    Body = 0x0304,
+}
+
+let bytes = rkyv::to_bytes::<Error>(&Kind::Body).unwrap();
+
+let archived = rkyv::access::<Archived<Kind>, Error>(&bytes).unwrap();
+
+assert_eq!(Some(Kind::Body), archived.get());
+assert!(rkyv::access::<Archived<Kind>, Error>(&[0x00, 0x00]).is_err());
+# }
+```
+
+#### `rusqlite` and `diesel`
+
+Enable the `rusqlite` feature to implement `ToSql` and `FromSql` of [rusqlite](https://crates.io/crates/rusqlite), or the `diesel` feature to implement `AsExpression`, `ToSql`, `FromSql` and `Queryable` of [Diesel](https://crates.io/crates/diesel). The traits are only implemented for the enums with the `sql = "value"` or `sql = "name"` attribute. With `sql = "value"`, a variant is stored as its value, in `SmallInt`, `Integer` or `BigInt` chosen by the type of the values for Diesel, and the values out of the range of `i64` fail to compile. With `sql = "name"`, a variant is stored as its name in `Text` instead. Loading an unknown value results in an `UnknownOrdinalError` or an `UnknownVariantNameError`. The `diesel` feature requires Rust 1.78, as diesel 2.2 does, and diesel 2.3 requires Rust 1.86.
+
+```rust
+# #[cfg(all(feature = "derive", feature = "rusqlite"))]
+# {
+use enum_ordinalize::Ordinalize;
+use rusqlite::Connection;
+
+#[derive(Debug, PartialEq, Eq, Ordinalize)]
+#[ordinalize(sql = "value")]
+#[repr(u16)]
+enum Kind {
+    Header = 0x0102,
+    Body = 0x0304,
+}
+
+#[derive(Debug, PartialEq, Eq, Ordinalize)]
+#[ordinalize(sql = "name")]
+enum Level {
+    Info = 2,
+    Warn = 1,
+}
+
+let connection = Connection::open_in_memory().unwrap();
+
+connection.execute_batch("CREATE TABLE records (kind INTEGER, level TEXT)").unwrap();
+connection.execute("INSERT INTO records VALUES (?1, ?2)", (Kind::Body, Level::Warn)).unwrap();
+
+let (kind, level): (Kind, Level) = connection
+    .query_row("SELECT kind, level FROM records", [], |row| Ok((row.get(0)?, row.get(1)?)))
+    .unwrap();
+
+assert_eq!(Kind::Body, kind);
+assert_eq!(Level::Warn, level);
+# }
+```
+
+#### `schemars` and `utoipa`
+
+Enable the `schemars` feature to implement `JsonSchema` of [schemars](https://crates.io/crates/schemars), or the `utoipa` feature to implement `PartialSchema` and `ToSchema` of [utoipa](https://crates.io/crates/utoipa). The traits are only implemented for the enums with the `schema = "value"` or `schema = "name"` attribute. With `schema = "value"`, the schema is an `integer` schema whose `enum` lists the values of the variants, with their names in `x-enum-varnames`. With `schema = "name"`, it is a `string` schema listing the names instead, which fits enums serialized by their names.
+
+```rust
+# #[cfg(all(feature = "derive", feature = "schemars"))]
+# {
+use enum_ordinalize::Ordinalize;
+use schemars::schema_for;
+use serde_json::json;
+
+#[derive(Ordinalize)]
+#[ordinalize(schema = "value")]
+enum Sign {
+    Negative = -1,
+    Positive = 1,
+}
+
+#[derive(Ordinalize)]
+#[ordinalize(schema = "name")]
+enum Level {
+    Info = 2,
+    Warn = 1,
+}
+
+let schema = schema_for!(Sign);
+
+assert_eq!(Some(&json!([-1, 1])), schema.get("enum"));
+assert_eq!(Some(&json!(["Negative", "Positive"])), schema.get("x-enum-varnames"));
+
+let schema = schema_for!(Level);
+
+assert_eq!(Some(&json!("string")), schema.get("type"));
+assert_eq!(Some(&json!(["Info", "Warn"])), schema.get("enum"));
+# }
+```
+
+#### `clap`
+
+Enable the `clap` feature and add the `clap` attribute to implement `ValueEnum` of [clap](https://crates.io/crates/clap), so the enum can replace a separate `#[derive(ValueEnum)]`. The possible values are the variant names in `kebab-case`, which can be changed by `clap(rename_all = "...")` with `"verbatim"`, `"lower"`, `"UPPER"`, `"PascalCase"`, `"camelCase"`, `"snake_case"` or `"SCREAMING_SNAKE_CASE"`. Add `ordinal` to accept the values of the variants too, which requires them to be integer literals. The first paragraph of the doc comment of a variant becomes its help, and the `clap(name = "...")` and `clap(hide)` attributes on a variant rename and hide it. clap 4.6 requires Rust 1.85, so pin an older version of clap with older Rust, such as 4.3, which supports Rust 1.68.
+
+```rust
+# #[cfg(all(feature = "derive", feature = "clap"))]
+# {
+use clap::Parser;
+use enum_ordinalize::Ordinalize;
+
+#[derive(Debug, Clone, PartialEq, Eq, Ordinalize)]
+#[ordinalize(clap(rename_all = "lower", ordinal))]
+enum Level {
+    /// Only errors.
+    Error = 0,
+    /// Errors and warnings.
+    Warn = 1,
+    #[ordinalize(clap(name = "all"))]
+    Verbose = 2,
+}
+
+#[derive(Parser)]
+struct Cli {
+    #[arg(long, value_enum)]
+    level: Level,
+}
+
+assert_eq!(Level::Warn, Cli::parse_from(["app", "--level", "warn"]).level);
+assert_eq!(Level::Warn, Cli::parse_from(["app", "--level", "1"]).level);
+assert_eq!(Level::Verbose, Cli::parse_from(["app", "--level", "all"]).level);
+# }
+```
+
+#### `tracing` and `valuable`
+
+`tracing::Value` is sealed, so the `tracing` feature records a variant as two fields instead, its name and its value. In events, use `variant_name` and `ordinal` directly. For spans, `enum_ordinalize::tracing::record` fills the `{field}` and `{field}.ordinal` fields.
+
+Enable the `valuable` feature to implement `Valuable` and `Enumerable` of [valuable](https://crates.io/crates/valuable) on the enums with the `valuable` attribute. The `EnumDef` lists the names of the variants, and every variant has one unnamed field, which is its value.
+
+```rust
+# #[cfg(all(feature = "derive", feature = "tracing"))]
+# {
+use enum_ordinalize::{tracing::record, Ordinalize, VariantNames};
+use tracing::field::Empty;
+
+#[derive(Ordinalize)]
+enum Kind {
+    Header = 1,
+    Body = 2,
+}
+
+let kind = Kind::Body;
+
+tracing::info!(kind = kind.variant_name(), kind.ordinal = kind.ordinal(), "received");
+
+let span = tracing::info_span!("request", kind = Empty, kind.ordinal = Empty);
+
+record(&span, "kind", &kind);
+# }
+```
+
+#### `defmt`
+
+Enable the `defmt` feature to implement `defmt::Format` of [defmt](https://crates.io/crates/defmt) on the enums with the `defmt = "name"` or `defmt = "ordinal"` attribute, which logs the interned name of a variant, or its value, respectively. The generated code uses the macros of `defmt`, so the crate using the derive macro should depend on `defmt` as well.
+
+```rust
+# #[cfg(all(feature = "derive", feature = "defmt"))]
+# {
+use enum_ordinalize::Ordinalize;
+
+#[derive(Ordinalize)]
+#[ordinalize(defmt = "name")]
+enum State {
+    Idle,
+    Busy,
+}
+
+#[derive(Ordinalize)]
+#[ordinalize(defmt = "ordinal")]
+#[repr(u8)]
+enum Register {
+    Control = 0x10,
+    Status = 0x11,
+}
+
+fn log(state: State, register: Register) {
+    defmt::println!("{} {}", state, register);
+}
+# }
+```
+
+#### `modular-bitfield`
+
+Implement `Specifier` of [modular-bitfield](https://crates.io/crates/modular-bitfield) with `BITS` bits on the enums with the `modular_bitfield` attribute, so ordinalized enums can be fields of `#[bitfield]` structs. Generic enums are not supported.
+
+```rust
+# #[cfg(all(feature = "derive", feature = "modular-bitfield"))]
+# {
+use enum_ordinalize::Ordinalize;
+use modular_bitfield::{bitfield, specifiers::B6};
+
+#[derive(Debug, PartialEq, Eq, Ordinalize)]
+#[ordinalize(modular_bitfield)]
+#[repr(u8)]
+enum Mode {
+    Input = 0b00,
+    Output = 0b01,
+    Analog = 0b11,
+}
+
+#[bitfield]
+struct Register {
+    mode: Mode,
+    #[skip]
+    reserved: B6,
+}
+
+let register = Register::new().with_mode(Mode::Analog);
+
+assert_eq!([0b11], register.into_bytes());
+assert_eq!(Mode::Output, Register::from_bytes([0b01]).mode());
+# }
+```
+
+#### `rand`
+
+Implement `Distribution<E>` for `rand::distributions::Standard` on the enums with the `rand` attribute, so random variants can be generated by `rng.gen()`. Variants are chosen uniformly unless some of them have the `weight` attribute, in which case the others have the weight `1`.
+
+```rust
+# #[cfg(all(feature = "derive", feature = "rand"))]
+# {
+use enum_ordinalize::Ordinalize;
+use rand::Rng;
+
+#[derive(Debug, PartialEq, Eq, Ordinalize)]
+#[ordinalize(rand)]
+enum Weather {
+    #[ordinalize(weight = 5)]
+    Sunny,
+    Cloudy,
+    #[ordinalize(weight = 0)]
+    Hurricane,
+}
+
+let weather: Weather = rand::thread_rng().gen();
+
+assert_ne!(Weather::Hurricane, weather);
+# }
+```
+
+#### `arbitrary`
+
+Implement `arbitrary::Arbitrary` for the ordinalized enums with the `arbitrary` attribute. Variants are chosen uniformly.
+
+#### `proptest`
+
+The `enum_ordinalize::proptest` module provides the `any_variant`, `any_valid_ordinal` and `any_invalid_ordinal` strategies. The last one is useful for testing how your code handles numbers which cannot be converted into variants. proptest 1.12 requires Rust 1.88, so pin an older version of proptest with older Rust, such as 1.7, which supports Rust 1.68.
+
+```rust
+# #[cfg(all(feature = "derive", feature = "proptest"))]
+# {
+use enum_ordinalize::{proptest::any_invalid_ordinal, Ordinalize};
+use proptest::{prop_assert, test_runner::TestRunner};
+
+#[derive(Debug, PartialEq, Eq, Ordinalize)]
+enum MyEnum {
+    A = 1,
+    B = 2,
+    C = 4,
+}
+
+TestRunner::default()
+    .run(&any_invalid_ordinal::<MyEnum>(), |number| {
+        prop_assert!(MyEnum::from_ordinal(number).is_none());
+
+        Ok(())
+    })
+    .unwrap();
+# }
+```
 */
 
 #![no_std]
 #![cfg_attr(docsrs, feature(doc_cfg))]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
+#[cfg(feature = "traits")]
+mod atomic;
+#[cfg(feature = "traits")]
+mod bits;
+#[cfg(all(feature = "traits", feature = "alloc"))]
+mod counter;
+#[cfg(feature = "traits")]
+mod cyclic;
+#[cfg(feature = "traits")]
+mod endian;
+#[cfg(feature = "traits")]
+mod error;
+#[cfg(feature = "traits")]
+mod macros;
+#[cfg(feature = "nom")]
+#[cfg_attr(docsrs, doc(cfg(feature = "nom")))]
+pub mod nom;
+#[cfg(feature = "traits")]
+mod ordinal_int;
+#[cfg(all(feature = "traits", feature = "alloc"))]
+mod packed_vec;
+#[cfg(feature = "proptest")]
+#[cfg_attr(docsrs, doc(cfg(feature = "proptest")))]
+pub mod proptest;
+#[cfg(feature = "traits")]
+mod range;
+#[cfg(feature = "rkyv")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rkyv")))]
+pub mod rkyv;
+#[cfg(all(feature = "traits", feature = "alloc"))]
+mod sort;
+#[cfg(feature = "tracing")]
+#[cfg_attr(docsrs, doc(cfg(feature = "tracing")))]
+pub mod tracing;
 #[cfg(feature = "traits")]
 mod traits;
+mod unknown_enum_value;
+#[cfg(feature = "traits")]
+mod variant_names;
 
+#[cfg(feature = "traits")]
+pub use atomic::{AtomicEnum, AtomicOrdinalInt};
+#[cfg(all(feature = "traits", feature = "alloc", target_has_atomic = "64"))]
+pub use counter::AtomicEnumCounter;
+#[cfg(all(feature = "traits", feature = "alloc"))]
+pub use counter::{EnumCounter, EnumCounterIter};
+#[cfg(feature = "traits")]
+pub use cyclic::Cyclic;
+#[cfg(feature = "traits")]
+pub use endian::{BigEndian, Endianness, LittleEndian, NativeEndian};
 #[cfg(feature = "derive")]
 pub use enum_ordinalize_derive::Ordinalize;
 #[cfg(feature = "traits")]
+pub use error::UnknownOrdinalError;
+#[cfg(all(feature = "traits", feature = "alloc"))]
+pub use error::UnknownVariantNameError;
+#[cfg(feature = "traits")]
+pub use ordinal_int::OrdinalInt;
+#[cfg(all(feature = "traits", feature = "alloc"))]
+pub use packed_vec::{PackedVec, PackedVecIter};
+#[cfg(feature = "traits")]
+pub use range::VariantRange;
+#[cfg(all(feature = "traits", feature = "alloc"))]
+pub use sort::{
+    group_by_variant, sort_by_key_variant, sort_by_key_variant_value, sort_by_variant,
+    sort_by_variant_value, VariantGroups, VariantGroupsIter,
+};
+#[cfg(feature = "traits")]
 pub use traits::Ordinalize;
+pub use unknown_enum_value::UnknownEnumValue;
+#[cfg(feature = "traits")]
+pub use variant_names::VariantNames;
+
+#[doc(hidden)]
+pub mod __private {
+    #[cfg(any(feature = "schemars", feature = "utoipa"))]
+    pub use alloc::borrow::Cow;
+
+    #[cfg(feature = "arbitrary")]
+    pub use arbitrary;
+    #[cfg(feature = "bincode")]
+    pub use bincode;
+    #[cfg(feature = "borsh")]
+    pub use borsh;
+    #[cfg(feature = "clap")]
+    pub use clap;
+    #[cfg(feature = "defmt")]
+    pub use defmt;
+    #[cfg(feature = "diesel")]
+    pub use diesel;
+    #[cfg(feature = "modular-bitfield")]
+    pub use modular_bitfield;
+    #[cfg(feature = "rand")]
+    pub use rand;
+    #[cfg(feature = "rkyv")]
+    pub use rkyv;
+    #[cfg(feature = "rusqlite")]
+    pub use rusqlite;
+    #[cfg(feature = "schemars")]
+    pub use schemars;
+    #[cfg(feature = "utoipa")]
+    pub use serde_json;
+    #[cfg(feature = "utoipa")]
+    pub use utoipa;
+    #[cfg(feature = "valuable")]
+    pub use valuable;
+
+    #[cfg(feature = "modular-bitfield")]
+    pub use crate::bits::{byte_count, ByteCount, SpecifierBytes};
+    #[cfg(feature = "traits")]
+    pub use crate::bits::{has_negative, index_bits, signed_bits, unsigned_bits, value_from_bits};
+    #[cfg(feature = "traits")]
+    pub use crate::variant_names::unraw;
+}
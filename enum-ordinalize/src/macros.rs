@@ -0,0 +1,327 @@
+/// Declare an enum and implement the `Ordinalize` trait for it without using the procedural macro.
+///
+/// The enum must have a `#[repr(type)]` attribute to determine the type of the values of the variants, and it can only have unit variants.
+///
+/// ```rust
+/// use enum_ordinalize::Ordinalize;
+///
+/// enum_ordinalize::ordinalize! {
+///     #[derive(Debug, PartialEq, Eq)]
+///     #[repr(u8)]
+///     pub enum MyEnum {
+///         A = 1,
+///         B,
+///         C,
+///     }
+/// }
+///
+/// assert_eq!(3, MyEnum::VARIANT_COUNT);
+/// assert_eq!([MyEnum::A, MyEnum::B, MyEnum::C], MyEnum::VARIANTS);
+/// assert_eq!([1u8, 2u8, 3u8], MyEnum::VALUES);
+///
+/// assert_eq!(2u8, MyEnum::B.ordinal());
+/// assert_eq!(Some(MyEnum::C), MyEnum::from_ordinal(3u8));
+/// assert_eq!(None, MyEnum::from_ordinal(4u8));
+/// ```
+///
+/// A variant is encoded as its value by `into_bits`, so the values must fit in 64 bits.
+///
+/// ```rust,compile_fail
+/// enum_ordinalize::ordinalize! {
+///     #[repr(u128)]
+///     pub enum Wide {
+///         Low = 1,
+///         High = 1 << 64,
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! ordinalize {
+    (@attrs [$($attrs:tt)*] [] #[repr($repr:ident)] $($rest:tt)*) => {
+        $crate::ordinalize!(@attrs [$($attrs)* #[repr($repr)]] [$repr] $($rest)*);
+    };
+    (@attrs [$($attrs:tt)*] [$($repr:ident)?] #[$attr:meta] $($rest:tt)*) => {
+        $crate::ordinalize!(@attrs [$($attrs)* #[$attr]] [$($repr)?] $($rest)*);
+    };
+    (
+        @attrs [$($attrs:tt)*] [$repr:ident]
+        $vis:vis enum $name:ident {
+            $($(#[$variant_attr:meta])* $variant:ident $(= $value:expr)?),+ $(,)?
+        }
+    ) => {
+        $($attrs)*
+        $vis enum $name {
+            $($(#[$variant_attr])* $variant $(= $value)?,)+
+        }
+
+        impl $crate::Ordinalize for $name {
+            type VariantType = $repr;
+
+            const VARIANT_COUNT: usize = Self::VARIANTS.len();
+
+            const VARIANTS: &'static [Self] = &[$(Self::$variant,)+];
+
+            const VALUES: &'static [$repr] = &[$(Self::$variant as $repr,)+];
+
+            const BITS: u32 = if <$repr>::MIN as i128 != 0 {
+                $crate::__private::signed_bits(&[$(Self::$variant as $repr as i128,)+])
+            } else {
+                $crate::__private::unsigned_bits(&[$(Self::$variant as $repr as u128,)+])
+            };
+
+            #[inline]
+            fn ordinal(&self) -> $repr {
+                match self {
+                    $(Self::$variant => Self::$variant as $repr,)+
+                }
+            }
+
+            #[inline]
+            unsafe fn from_ordinal_unsafe(number: $repr) -> Self {
+                unsafe { ::core::mem::transmute(number) }
+            }
+
+            #[inline]
+            fn from_ordinal(number: $repr) -> Option<Self> {
+                $(
+                    if number == Self::$variant as $repr {
+                        return Some(Self::$variant);
+                    }
+                )+
+
+                None
+            }
+
+            #[inline]
+            fn variant_index(&self) -> usize {
+                // the discriminants of this enum are the indexes of the variants
+                #[allow(dead_code, non_camel_case_types)]
+                enum __VariantIndex {
+                    $($variant,)+
+                }
+
+                match self {
+                    $(Self::$variant => __VariantIndex::$variant as usize,)+
+                }
+            }
+
+            #[inline]
+            fn from_variant_index(index: usize) -> Option<Self> {
+                #[allow(dead_code, non_camel_case_types)]
+                enum __VariantIndex {
+                    $($variant,)+
+                }
+
+                $(
+                    if index == __VariantIndex::$variant as usize {
+                        return Some(Self::$variant);
+                    }
+                )+
+
+                None
+            }
+
+            #[inline]
+            fn into_bits(&self) -> u64 {
+                $crate::OrdinalInt::to_bits(self.ordinal(), Self::BITS)
+            }
+
+            #[inline]
+            fn from_bits(bits: u64) -> Option<Self> {
+                // the values are encoded in two's complement only if any of them is negative
+                const NEGATIVE: bool = <$repr>::MIN as i128 != 0
+                    && $crate::__private::has_negative(&[$($name::$variant as $repr as i128,)+]);
+
+                Self::from_ordinal($crate::__private::value_from_bits(bits, Self::BITS, NEGATIVE)?)
+            }
+        }
+
+        const _: () = ::core::assert!(
+            <$name as $crate::Ordinalize>::BITS <= u64::BITS,
+            ::core::concat!("the values of the variants of `", ::core::stringify!($name), "` need more than 64 bits")
+        );
+
+        impl $crate::VariantNames for $name {
+            const VARIANT_NAMES: &'static [&'static str] = &[$($crate::__private::unraw(::core::stringify!($variant)),)+];
+        }
+    };
+    (@attrs [$($attrs:tt)*] [] $vis:vis enum $($rest:tt)*) => {
+        ::core::compile_error!("an enum declared by `ordinalize!` needs the `repr` attribute to determine the type of the values of its variants");
+    };
+    ($($input:tt)*) => {
+        $crate::ordinalize!(@attrs [] [] $($input)*);
+    };
+}
+
+/// Declare a table which holds a value for each variant of an ordinalized enum, in the order of `VARIANTS`, and can be read in const contexts.
+///
+/// The entries must list every variant exactly once, in declaration order, or the table fails to compile. The declared type is a unit struct which has the `TABLE` constant and the `const fn get` method, which returns a copy of the value of a variant, so the type of the values should implement `Copy`.
+///
+/// Without the `struct` declaration, the macro is an expression which evaluates to a table of an unnamed type.
+///
+/// ```rust
+/// use enum_ordinalize::Ordinalize;
+///
+/// enum_ordinalize::ordinalize! {
+///     #[derive(Debug, PartialEq, Eq)]
+///     #[repr(u8)]
+///     pub enum Format {
+///         Json,
+///         Toml,
+///         Yaml,
+///     }
+/// }
+///
+/// enum_ordinalize::enum_table! {
+///     pub struct MimeTypes: Format => &'static str {
+///         Json => "application/json",
+///         Toml => "application/toml",
+///         Yaml => "application/yaml",
+///     }
+/// }
+///
+/// const TOML: &str = MimeTypes.get(&Format::Toml);
+///
+/// assert_eq!("application/toml", TOML);
+/// assert_eq!(
+///     "application/yaml",
+///     MimeTypes::TABLE[Format::Yaml.variant_index()]
+/// );
+///
+/// let extensions = enum_ordinalize::enum_table!(Format => &'static str {
+///     Json => "json",
+///     Toml => "toml",
+///     Yaml => "yaml",
+/// });
+///
+/// assert_eq!("yaml", extensions.get(&Format::Yaml));
+/// ```
+///
+/// A missing variant:
+///
+/// ```rust,compile_fail
+/// use enum_ordinalize::Ordinalize;
+///
+/// enum_ordinalize::ordinalize! {
+///     #[repr(u8)]
+///     pub enum Format {
+///         Json,
+///         Toml,
+///     }
+/// }
+///
+/// enum_ordinalize::enum_table! {
+///     pub struct MimeTypes: Format => &'static str {
+///         Json => "application/json",
+///     }
+/// }
+/// ```
+///
+/// Entries out of declaration order:
+///
+/// ```rust,compile_fail
+/// use enum_ordinalize::Ordinalize;
+///
+/// enum_ordinalize::ordinalize! {
+///     #[repr(u8)]
+///     pub enum Format {
+///         Json,
+///         Toml,
+///     }
+/// }
+///
+/// enum_ordinalize::enum_table! {
+///     pub struct MimeTypes: Format => &'static str {
+///         Toml => "application/toml",
+///         Json => "application/json",
+///     }
+/// }
+/// ```
+///
+/// A duplicate variant:
+///
+/// ```rust,compile_fail
+/// use enum_ordinalize::Ordinalize;
+///
+/// enum_ordinalize::ordinalize! {
+///     #[repr(u8)]
+///     pub enum Format {
+///         Json,
+///         Toml,
+///     }
+/// }
+///
+/// enum_ordinalize::enum_table! {
+///     pub struct MimeTypes: Format => &'static str {
+///         Json => "application/json",
+///         Json => "text/json",
+///         Toml => "application/toml",
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! enum_table {
+    (@match $input:expr; $enum:ty; [$($arms:tt)*] [$($index:tt)*];) => {
+        {
+            #[deny(unreachable_patterns)]
+            let index = match $input {
+                $($arms)*
+            };
+
+            index
+        }
+    };
+    (@match $input:expr; $enum:ty; [$($arms:tt)*] [$($index:tt)*]; $variant:ident $(, $rest:ident)*) => {
+        $crate::enum_table!(@match $input; $enum; [$($arms)* <$enum>::$variant => $($index)*,] [$($index)* + 1]; $($rest),*)
+    };
+    (
+        $(#[$attr:meta])*
+        $vis:vis struct $table:ident: $enum:ty => $value:ty {
+            $($variant:ident => $variant_value:expr),+ $(,)?
+        }
+    ) => {
+        $(#[$attr])*
+        $vis struct $table;
+
+        impl $table {
+            /// The values of the variants, in the order of `VARIANTS`.
+            pub const TABLE: [$value; <$enum as $crate::Ordinalize>::VARIANT_COUNT] = {
+                let mut index = 0;
+
+                $(
+                    ::core::assert!(
+                        index < <$enum as $crate::Ordinalize>::VARIANT_COUNT
+                            && ::core::matches!(&<$enum as $crate::Ordinalize>::VARIANTS[index], <$enum>::$variant),
+                        ::core::concat!("the entries of `", ::core::stringify!($table), "` should list the variants in declaration order, but `", ::core::stringify!($variant), "` is out of place")
+                    );
+
+                    index += 1;
+                )+
+
+                let _ = index;
+
+                [$($variant_value),+]
+            };
+
+            /// Return the value of a variant.
+            #[inline]
+            pub const fn get(&self, value: &$enum) -> $value {
+                Self::TABLE[$crate::enum_table!(@match value; $enum; [] [0]; $($variant),+)]
+            }
+        }
+
+        // check the entries even if the table is never used
+        const _: [$value; <$enum as $crate::Ordinalize>::VARIANT_COUNT] = $table::TABLE;
+    };
+    ($enum:ty => $value:ty { $($variant:ident => $variant_value:expr),+ $(,)? }) => {
+        {
+            $crate::enum_table! {
+                struct __EnumTable: $enum => $value {
+                    $($variant => $variant_value),+
+                }
+            }
+
+            __EnumTable
+        }
+    };
+}
@@ -0,0 +1,99 @@
+/*!
+Parsers for ordinalized enums built on [`nom`](https://crates.io/crates/nom).
+
+The parsers work on complete input. The input and error types are generic and usually inferred, so they can be left as `_`.
+
+```rust
+# #[cfg(feature = "derive")]
+# {
+use enum_ordinalize::{nom::*, BigEndian, Ordinalize};
+use nom::{IResult, Parser};
+
+#[derive(Debug, PartialEq, Eq, Ordinalize)]
+#[repr(u16)]
+enum Kind {
+    Header = 0x0102,
+    HeaderExtension = 0x0103,
+    Body = 0x0304,
+}
+
+let result: IResult<&[u8], Kind> = ordinal::<Kind, BigEndian, _, _>().parse(&[0x03, 0x04, 0xFF]);
+assert_eq!(Ok((&[0xFF][..], Kind::Body)), result);
+
+let result: IResult<&str, Kind> = name::<Kind, _, _>().parse("HeaderExtension: 1");
+assert_eq!(Ok((": 1", Kind::HeaderExtension)), result);
+# }
+```
+*/
+
+use core::mem::size_of;
+
+use ::nom::{
+    bytes::complete::take,
+    error::{ErrorKind, FromExternalError, ParseError},
+    Compare, CompareResult, Err, IResult, Input,
+};
+
+use crate::{Endianness, OrdinalInt, Ordinalize, UnknownOrdinalError, VariantNames};
+
+/// Parse an ordinal from as many bytes as the size of `E::VariantType` in the byte order `En`, and obtain the corresponding variant.
+///
+/// If the ordinal does not correspond to any variant, an error of the [`ErrorKind::MapRes`] kind is returned with the input before the ordinal, and the [`UnknownOrdinalError`] is passed to [`FromExternalError`].
+pub fn ordinal<E, En, I, Error>() -> impl FnMut(I) -> IResult<I, E, Error>
+where
+    E: Ordinalize,
+    En: Endianness,
+    I: Input<Item = u8>,
+    Error: ParseError<I> + FromExternalError<I, UnknownOrdinalError<E>>, {
+    move |input: I| {
+        let (rest, bytes_input) = take(size_of::<E::VariantType>())(input.clone())?;
+
+        let mut bytes = <E::VariantType as OrdinalInt>::Bytes::default();
+
+        for (byte, element) in bytes.as_mut().iter_mut().zip(bytes_input.iter_elements()) {
+            *byte = element;
+        }
+
+        let number = En::from_bytes::<E::VariantType>(bytes);
+
+        match E::from_ordinal(number) {
+            Some(variant) => Ok((rest, variant)),
+            None => Err(Err::Error(Error::from_external_error(
+                input,
+                ErrorKind::MapRes,
+                UnknownOrdinalError::new(number),
+            ))),
+        }
+    }
+}
+
+/// Parse the name of a variant and obtain the variant.
+///
+/// When several names are prefixes of the input, the longest one is matched. If no name matches, an error of the [`ErrorKind::Tag`] kind is returned.
+pub fn name<E, I, Error>() -> impl FnMut(I) -> IResult<I, E, Error>
+where
+    E: VariantNames,
+    I: Input + Compare<&'static str>,
+    Error: ParseError<I>, {
+    move |input: I| {
+        let mut matched: Option<(usize, usize)> = None;
+
+        for (index, name) in E::VARIANT_NAMES.iter().enumerate() {
+            if let CompareResult::Ok = input.compare(*name) {
+                match matched {
+                    Some((_, len)) if len >= name.len() => (),
+                    _ => matched = Some((index, name.len())),
+                }
+            }
+        }
+
+        match matched {
+            Some((index, len)) => {
+                let (rest, _) = input.take_split(len);
+
+                Ok((rest, E::from_variant_index(index).unwrap()))
+            },
+            None => Err(Err::Error(Error::from_error_kind(input, ErrorKind::Tag))),
+        }
+    }
+}
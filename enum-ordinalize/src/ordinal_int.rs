@@ -0,0 +1,169 @@
+use core::{
+    fmt::{Debug, Display},
+    hash::Hash,
+};
+
+mod private {
+    pub trait Sealed {}
+}
+
+/// This trait is implemented for all primitive integer types which can be the type of the values of an ordinalized enum's variants.
+///
+/// It cannot be implemented outside this crate.
+///
+/// ```rust
+/// use enum_ordinalize::{OrdinalInt, Ordinalize};
+///
+/// fn describe<E: Ordinalize>(e: &E) -> (i128, u32) {
+///     (e.ordinal().to_i128().unwrap(), E::VariantType::BITS)
+/// }
+/// ```
+pub trait OrdinalInt:
+    Copy + Eq + Ord + Hash + Debug + Display + Default + Send + Sync + 'static + private::Sealed {
+    /// The size of this integer type in bits.
+    const BITS: u32;
+
+    /// The smallest value that can be represented by this integer type.
+    const MIN: Self;
+
+    /// The largest value that can be represented by this integer type.
+    const MAX: Self;
+
+    /// Convert this integer to `i128` without truncation. `None` is returned if it is greater than `i128::MAX`.
+    fn to_i128(self) -> Option<i128>;
+
+    /// Convert an `i128` integer to this integer type without truncation. `None` is returned if it is out of range.
+    fn try_from_i128(number: i128) -> Option<Self>;
+
+    /// The byte array which has the same size as this integer type.
+    type Bytes: Copy
+        + Eq
+        + Hash
+        + Debug
+        + Default
+        + AsRef<[u8]>
+        + AsMut<[u8]>
+        + Send
+        + Sync
+        + 'static;
+
+    /// Return the memory representation of this integer as a byte array in little-endian byte order.
+    fn to_le_bytes(self) -> Self::Bytes;
+
+    /// Return the memory representation of this integer as a byte array in big-endian byte order.
+    fn to_be_bytes(self) -> Self::Bytes;
+
+    /// Return the memory representation of this integer as a byte array in native byte order.
+    fn to_ne_bytes(self) -> Self::Bytes;
+
+    /// Create an integer from its representation as a byte array in little endian.
+    fn from_le_bytes(bytes: Self::Bytes) -> Self;
+
+    /// Create an integer from its representation as a byte array in big endian.
+    fn from_be_bytes(bytes: Self::Bytes) -> Self;
+
+    /// Create an integer from its representation as a byte array in native endianness.
+    fn from_ne_bytes(bytes: Self::Bytes) -> Self;
+
+    /// Return the lowest `width` bits of this integer, which is its two's complement representation if it is negative. `width` should not be more than 64, or the higher bits are dropped.
+    fn to_bits(self, width: u32) -> u64;
+
+    /// Create an integer from its lowest `width` bits, which are sign-extended for signed types. `None` is returned if `width` is more than 64, any higher bit is set or the integer is out of range.
+    fn from_bits(bits: u64, width: u32) -> Option<Self>;
+}
+
+#[inline]
+const fn bit_mask(width: u32) -> u64 {
+    if width >= u64::BITS {
+        u64::MAX
+    } else {
+        (1 << width) - 1
+    }
+}
+
+macro_rules! impl_ordinal_int {
+    ($($t:ty),* $(,)*) => {
+        $(
+            impl private::Sealed for $t {}
+
+            impl OrdinalInt for $t {
+                type Bytes = [u8; ::core::mem::size_of::<$t>()];
+
+                const BITS: u32 = <$t>::BITS;
+                const MAX: Self = <$t>::MAX;
+                const MIN: Self = <$t>::MIN;
+
+                #[inline]
+                fn to_i128(self) -> Option<i128> {
+                    i128::try_from(self).ok()
+                }
+
+                #[inline]
+                fn try_from_i128(number: i128) -> Option<Self> {
+                    <$t>::try_from(number).ok()
+                }
+
+                #[inline]
+                fn to_le_bytes(self) -> Self::Bytes {
+                    <$t>::to_le_bytes(self)
+                }
+
+                #[inline]
+                fn to_be_bytes(self) -> Self::Bytes {
+                    <$t>::to_be_bytes(self)
+                }
+
+                #[inline]
+                fn to_ne_bytes(self) -> Self::Bytes {
+                    <$t>::to_ne_bytes(self)
+                }
+
+                #[inline]
+                fn from_le_bytes(bytes: Self::Bytes) -> Self {
+                    <$t>::from_le_bytes(bytes)
+                }
+
+                #[inline]
+                fn from_be_bytes(bytes: Self::Bytes) -> Self {
+                    <$t>::from_be_bytes(bytes)
+                }
+
+                #[inline]
+                fn from_ne_bytes(bytes: Self::Bytes) -> Self {
+                    <$t>::from_ne_bytes(bytes)
+                }
+
+                #[inline]
+                fn to_bits(self, width: u32) -> u64 {
+                    (self as u64) & bit_mask(width)
+                }
+
+                #[inline]
+                fn from_bits(bits: u64, width: u32) -> Option<Self> {
+                    if width > u64::BITS {
+                        return None;
+                    }
+
+                    let mask = bit_mask(width);
+
+                    if bits & !mask != 0 {
+                        return None;
+                    }
+
+                    #[allow(unused_comparisons)]
+                    if <$t>::MIN < 0 {
+                        let sign_bit = 1 << (width.max(1) - 1);
+
+                        let bits = if bits & sign_bit != 0 { bits | !mask } else { bits };
+
+                        <$t>::try_from(bits as i64).ok()
+                    } else {
+                        <$t>::try_from(bits).ok()
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_ordinal_int!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
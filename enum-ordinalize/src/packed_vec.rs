@@ -0,0 +1,359 @@
+use alloc::vec::Vec;
+use core::{
+    fmt::{self, Debug, Formatter},
+    iter::FusedIterator,
+    marker::PhantomData,
+};
+
+use crate::{bits::index_bits, Ordinalize};
+
+const WORD_BITS: usize = u64::BITS as usize;
+
+/// A vector which stores the variants of an ordinalized enum by their indexes in `VARIANTS`, each in [`PackedVec::ELEMENT_BITS`] bits.
+///
+/// The bits of the elements are packed into `u64` words from the least significant bit, and an element can span two words. The bits after the last element are always zero, so two vectors with the same elements have the same words.
+///
+/// ```rust
+/// # #[cfg(feature = "derive")]
+/// # {
+/// use enum_ordinalize::{Ordinalize, PackedVec};
+///
+/// #[derive(Debug, PartialEq, Eq, Ordinalize)]
+/// enum Tile {
+///     Empty,
+///     Wall,
+///     Water,
+///     Grass,
+///     Lava,
+/// }
+///
+/// let mut tiles: PackedVec<Tile> =
+///     [Tile::Wall, Tile::Lava].into_iter().collect();
+///
+/// tiles.push(Tile::Grass);
+/// tiles.set(0, Tile::Water);
+///
+/// assert_eq!(3, PackedVec::<Tile>::ELEMENT_BITS);
+/// assert_eq!(Some(Tile::Water), tiles.get(0));
+/// assert_eq!(
+///     vec![Tile::Water, Tile::Lava, Tile::Grass],
+///     tiles.iter().collect::<Vec<_>>()
+/// );
+/// assert_eq!(&[0b011_100_010], tiles.as_raw_words());
+/// # }
+/// ```
+pub struct PackedVec<E: Ordinalize> {
+    words:   Vec<u64>,
+    len:     usize,
+    _marker: PhantomData<fn() -> E>,
+}
+
+impl<E: Ordinalize> PackedVec<E> {
+    /// The number of bits of an element, which is the minimum number of bits to encode the index of every variant, and at least 1.
+    pub const ELEMENT_BITS: usize = index_bits(E::VARIANT_COUNT) as usize;
+
+    /// Create an empty vector.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            words: Vec::new(), len: 0, _marker: PhantomData
+        }
+    }
+
+    /// Create an empty vector which can hold at least `capacity` elements without reallocating.
+    #[inline]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            words:   Vec::with_capacity(Self::words_for(capacity).expect("capacity overflow")),
+            len:     0,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Create a vector from the words returned by [`PackedVec::as_raw_words`] and the number of elements.
+    ///
+    /// `None` is returned if the number of words does not match, which includes the number of bits of `len` elements overflowing `usize`, any element is not the index of a variant, or any bit after the last element is set.
+    pub fn from_raw_words(words: Vec<u64>, len: usize) -> Option<Self> {
+        if Self::words_for(len) != Some(words.len()) {
+            return None;
+        }
+
+        let packed_vec = Self {
+            words,
+            len,
+            _marker: PhantomData,
+        };
+
+        let used_bits = len * Self::ELEMENT_BITS % WORD_BITS;
+
+        if used_bits > 0 && packed_vec.words[packed_vec.words.len() - 1] >> used_bits != 0 {
+            return None;
+        }
+
+        if (0..len).any(|index| packed_vec.read(index) >= E::VARIANT_COUNT) {
+            return None;
+        }
+
+        Some(packed_vec)
+    }
+
+    /// Return the words which store the elements.
+    #[inline]
+    pub fn as_raw_words(&self) -> &[u64] {
+        &self.words
+    }
+
+    /// Return the number of elements.
+    #[inline]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Return `true` if there is no element.
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Append an element.
+    #[inline]
+    pub fn push(&mut self, value: E) {
+        let index = self.len;
+
+        self.len += 1;
+        self.words.resize(Self::words_for(self.len).expect("capacity overflow"), 0);
+
+        self.write(index, value.variant_index());
+    }
+
+    /// Remove the last element and return it.
+    #[inline]
+    pub fn pop(&mut self) -> Option<E> {
+        if self.len == 0 {
+            return None;
+        }
+
+        let index = self.len - 1;
+        let variant_index = self.read(index);
+
+        // keep the bits after the last element zero
+        self.write(index, 0);
+        self.len = index;
+        self.words.truncate(Self::words_for(self.len).expect("capacity overflow"));
+
+        E::from_variant_index(variant_index)
+    }
+
+    /// Return the element at `index`, or `None` if it is out of bounds.
+    #[inline]
+    pub fn get(&self, index: usize) -> Option<E> {
+        if index < self.len {
+            E::from_variant_index(self.read(index))
+        } else {
+            None
+        }
+    }
+
+    /// Replace the element at `index`.
+    ///
+    /// # Panics
+    /// Panics if `index` is out of bounds.
+    #[inline]
+    pub fn set(&mut self, index: usize, value: E) {
+        assert!(index < self.len, "the index is {index} but the length is {}", self.len);
+
+        self.write(index, value.variant_index());
+    }
+
+    /// Remove all elements.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.words.clear();
+        self.len = 0;
+    }
+
+    /// Iterate over the elements.
+    #[inline]
+    pub fn iter(&self) -> PackedVecIter<'_, E> {
+        PackedVecIter {
+            packed_vec: self, front: 0, back: self.len
+        }
+    }
+
+    /// Return the number of words to store `len` elements, or `None` if their number of bits overflows `usize`.
+    #[inline]
+    fn words_for(len: usize) -> Option<usize> {
+        let bits = len.checked_mul(Self::ELEMENT_BITS)?;
+
+        Some(bits / WORD_BITS + usize::from(bits % WORD_BITS != 0))
+    }
+
+    #[inline]
+    fn mask() -> u64 {
+        (1 << Self::ELEMENT_BITS) - 1
+    }
+
+    fn read(&self, index: usize) -> usize {
+        let position = index * Self::ELEMENT_BITS;
+        let (word, offset) = (position / WORD_BITS, position % WORD_BITS);
+
+        let mut bits = self.words[word] >> offset;
+
+        if offset + Self::ELEMENT_BITS > WORD_BITS {
+            bits |= self.words[word + 1] << (WORD_BITS - offset);
+        }
+
+        (bits & Self::mask()) as usize
+    }
+
+    fn write(&mut self, index: usize, variant_index: usize) {
+        let bits = variant_index as u64;
+        let position = index * Self::ELEMENT_BITS;
+        let (word, offset) = (position / WORD_BITS, position % WORD_BITS);
+
+        self.words[word] = (self.words[word] & !(Self::mask() << offset)) | (bits << offset);
+
+        if offset + Self::ELEMENT_BITS > WORD_BITS {
+            let shift = WORD_BITS - offset;
+
+            self.words[word + 1] =
+                (self.words[word + 1] & !(Self::mask() >> shift)) | (bits >> shift);
+        }
+    }
+}
+
+impl<E: Ordinalize> Default for PackedVec<E> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<E: Ordinalize> Clone for PackedVec<E> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            words: self.words.clone(), len: self.len, _marker: PhantomData
+        }
+    }
+}
+
+impl<E: Ordinalize> PartialEq for PackedVec<E> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len && self.words == other.words
+    }
+}
+
+impl<E: Ordinalize> Eq for PackedVec<E> {}
+
+impl<E: Ordinalize + Debug> Debug for PackedVec<E> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<E: Ordinalize> Extend<E> for PackedVec<E> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = E>>(&mut self, iter: I) {
+        let iter = iter.into_iter();
+
+        let words = Self::words_for(self.len.saturating_add(iter.size_hint().0))
+            .expect("capacity overflow");
+
+        self.words.reserve(words - self.words.len());
+
+        for value in iter {
+            self.push(value);
+        }
+    }
+}
+
+impl<E: Ordinalize> FromIterator<E> for PackedVec<E> {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = E>>(iter: I) -> Self {
+        let mut packed_vec = Self::new();
+
+        packed_vec.extend(iter);
+
+        packed_vec
+    }
+}
+
+impl<'a, E: Ordinalize> IntoIterator for &'a PackedVec<E> {
+    type IntoIter = PackedVecIter<'a, E>;
+    type Item = E;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// An iterator over the elements of a [`PackedVec`].
+pub struct PackedVecIter<'a, E: Ordinalize> {
+    packed_vec: &'a PackedVec<E>,
+    front:      usize,
+    back:       usize,
+}
+
+impl<'a, E: Ordinalize> Clone for PackedVecIter<'a, E> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            packed_vec: self.packed_vec, front: self.front, back: self.back
+        }
+    }
+}
+
+impl<'a, E: Ordinalize> Debug for PackedVecIter<'a, E> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PackedVecIter")
+            .field("front", &self.front)
+            .field("back", &self.back)
+            .finish()
+    }
+}
+
+impl<'a, E: Ordinalize> Iterator for PackedVecIter<'a, E> {
+    type Item = E;
+
+    #[inline]
+    fn next(&mut self) -> Option<E> {
+        if self.front < self.back {
+            let index = self.front;
+
+            self.front += 1;
+
+            self.packed_vec.get(index)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.back - self.front;
+
+        (len, Some(len))
+    }
+}
+
+impl<'a, E: Ordinalize> DoubleEndedIterator for PackedVecIter<'a, E> {
+    #[inline]
+    fn next_back(&mut self) -> Option<E> {
+        if self.front < self.back {
+            self.back -= 1;
+
+            self.packed_vec.get(self.back)
+        } else {
+            None
+        }
+    }
+}
+
+impl<'a, E: Ordinalize> ExactSizeIterator for PackedVecIter<'a, E> {}
+
+impl<'a, E: Ordinalize> FusedIterator for PackedVecIter<'a, E> {}
@@ -0,0 +1,100 @@
+/*!
+Strategies for testing ordinalized enums with [`proptest`](https://crates.io/crates/proptest).
+
+```rust
+# #[cfg(feature = "derive")]
+# {
+use enum_ordinalize::{proptest::*, Ordinalize};
+use proptest::{prop_assert, test_runner::TestRunner};
+
+#[derive(Debug, PartialEq, Eq, Ordinalize)]
+enum MyEnum {
+    A = 1,
+    B = 2,
+    C = 4,
+}
+
+let mut runner = TestRunner::default();
+
+runner
+    .run(&any_invalid_ordinal::<MyEnum>(), |number| {
+        prop_assert!(MyEnum::from_ordinal(number).is_none());
+
+        Ok(())
+    })
+    .unwrap();
+# }
+```
+*/
+
+use alloc::vec::Vec;
+use core::{any::type_name, fmt::Debug};
+
+use ::proptest::strategy::{BoxedStrategy, Just, Strategy, Union};
+
+use crate::{OrdinalInt, Ordinalize};
+
+/// Generate variants uniformly. They shrink toward the first variant.
+#[inline]
+pub fn any_variant<E: Ordinalize + Debug>() -> impl Strategy<Value = E> {
+    (0..E::VARIANT_COUNT).prop_map(|index| E::from_variant_index(index).unwrap())
+}
+
+/// Generate the values of the variants uniformly. They shrink toward the value of the first variant.
+#[inline]
+pub fn any_valid_ordinal<E: Ordinalize>() -> impl Strategy<Value = E::VariantType> {
+    (0..E::VARIANT_COUNT).prop_map(|index| E::VALUES[index])
+}
+
+/// Generate values of `E::VariantType` which do not correspond to any variant.
+///
+/// Every gap between the values of the variants (and the bounds of `E::VariantType`) is chosen equally, and the boundaries of the gaps are generated more often than the numbers inside. For `u128`, numbers greater than `i128::MAX` are not generated.
+///
+/// # Panics
+///
+/// Panics if every value of `E::VariantType` corresponds to a variant.
+pub fn any_invalid_ordinal<E: Ordinalize>() -> impl Strategy<Value = E::VariantType> {
+    let min = E::VariantType::MIN.to_i128().unwrap();
+    let max = E::VariantType::MAX.to_i128().unwrap_or(i128::MAX);
+
+    let mut values: Vec<i128> = E::VALUES.iter().filter_map(|value| value.to_i128()).collect();
+
+    values.sort_unstable();
+    values.dedup();
+
+    let mut strategies: Vec<BoxedStrategy<i128>> = Vec::new();
+    let mut start = Some(min);
+
+    for value in values {
+        if let Some(start) = start {
+            if start < value {
+                push_gap(&mut strategies, start, value - 1);
+            }
+        }
+
+        start = value.checked_add(1);
+    }
+
+    if let Some(start) = start {
+        if start <= max {
+            push_gap(&mut strategies, start, max);
+        }
+    }
+
+    if strategies.is_empty() {
+        panic!(
+            "every value of `{}` is a valid ordinal of `{}`",
+            type_name::<E::VariantType>(),
+            type_name::<E>()
+        );
+    }
+
+    Union::new(strategies).prop_map(|number| E::VariantType::try_from_i128(number).unwrap())
+}
+
+#[inline]
+fn push_gap(strategies: &mut Vec<BoxedStrategy<i128>>, start: i128, end: i128) {
+    strategies.push(Just(start).boxed());
+    strategies.push(Just(end).boxed());
+    strategies.push((start..=end).boxed());
+}
@@ -0,0 +1,100 @@
+use core::{
+    fmt::{self, Debug, Formatter},
+    iter::FusedIterator,
+    marker::PhantomData,
+    ops::{Bound, RangeBounds},
+};
+
+use crate::Ordinalize;
+
+/// An iterator over the variants of an ordinalized enum between two bounds, in declaration order.
+///
+/// It is created by the [`Ordinalize::range`] method.
+pub struct VariantRange<E: Ordinalize> {
+    front:   usize,
+    back:    usize,
+    _marker: PhantomData<fn() -> E>,
+}
+
+impl<E: Ordinalize> VariantRange<E> {
+    #[inline]
+    pub(crate) fn new<R: RangeBounds<E>>(range: R) -> Self {
+        let front = match range.start_bound() {
+            Bound::Included(start) => start.variant_index(),
+            Bound::Excluded(start) => start.variant_index() + 1,
+            Bound::Unbounded => 0,
+        };
+
+        let back = match range.end_bound() {
+            Bound::Included(end) => end.variant_index() + 1,
+            Bound::Excluded(end) => end.variant_index(),
+            Bound::Unbounded => E::VARIANT_COUNT,
+        };
+
+        Self {
+            front,
+            back: back.max(front),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<E: Ordinalize> Clone for VariantRange<E> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            front: self.front, back: self.back, _marker: PhantomData
+        }
+    }
+}
+
+impl<E: Ordinalize> Debug for VariantRange<E> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("VariantRange")
+            .field("front", &self.front)
+            .field("back", &self.back)
+            .finish()
+    }
+}
+
+impl<E: Ordinalize> Iterator for VariantRange<E> {
+    type Item = E;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front < self.back {
+            let variant = E::from_variant_index(self.front);
+
+            self.front += 1;
+
+            variant
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.back - self.front;
+
+        (len, Some(len))
+    }
+}
+
+impl<E: Ordinalize> DoubleEndedIterator for VariantRange<E> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front < self.back {
+            self.back -= 1;
+
+            E::from_variant_index(self.back)
+        } else {
+            None
+        }
+    }
+}
+
+impl<E: Ordinalize> ExactSizeIterator for VariantRange<E> {}
+
+impl<E: Ordinalize> FusedIterator for VariantRange<E> {}
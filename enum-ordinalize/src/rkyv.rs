@@ -0,0 +1,290 @@
+/*!
+Support for zero-copy deserialization with [`rkyv`](https://crates.io/crates/rkyv).
+
+With the `rkyv` feature, `#[derive(Ordinalize)]` implements `Archive` and `Serialize` for the enums with the `rkyv` attribute. Its archived form is [`ArchivedOrdinal`], which is the archived value of the variant, and validating an archive rejects any value that does not correspond to a variant.
+
+```rust
+# #[cfg(feature = "derive")]
+# {
+use enum_ordinalize::Ordinalize;
+use rkyv::rancor::Error;
+
+#[derive(Debug, PartialEq, Eq, Ordinalize)]
+#[ordinalize(rkyv)]
+#[repr(u16)]
+enum Kind {
+    Header = 0x0102,
+    Body = 0x0304,
+}
+
+let bytes = rkyv::to_bytes::<Error>(&Kind::Body).unwrap();
+
+let archived = rkyv::access::<rkyv::Archived<Kind>, Error>(&bytes).unwrap();
+
+assert_eq!(0x0304, archived.ordinal());
+assert_eq!(Some(Kind::Body), archived.get());
+assert_eq!(Kind::Body, rkyv::deserialize::<Kind, Error>(archived).unwrap());
+
+assert!(rkyv::access::<rkyv::Archived<Kind>, Error>(&[0x00, 0x00]).is_err());
+# }
+```
+
+This feature requires Rust 1.81, as rkyv 0.8 does.
+*/
+
+use core::{
+    any::type_name,
+    fmt::{self, Debug, Display, Formatter},
+    marker::PhantomData,
+};
+
+use ::rkyv::{
+    bytecheck::CheckBytes,
+    primitive::{FixedIsize, FixedUsize},
+    rancor::{fail, Fallible, Source},
+    Archive, Archived, Deserialize, Place, Portable,
+};
+
+use crate::{OrdinalInt, Ordinalize, UnknownOrdinalError};
+
+mod private {
+    pub trait Sealed {}
+}
+
+/// This trait is implemented for all primitive integer types which can be the type of the values of an ordinalized enum's variants, to read them from their archived form.
+///
+/// It cannot be implemented outside this crate.
+pub trait ArchivedOrdinalInt: OrdinalInt + Archive<Resolver = ()> + private::Sealed {
+    /// Read an integer from its archived form.
+    fn from_archived(archived: &Archived<Self>) -> Self;
+
+    #[doc(hidden)]
+    #[inline]
+    fn fits_archived(_value: Self) -> bool {
+        true
+    }
+}
+
+macro_rules! impl_archived_ordinal_int {
+    ($($t:ty => |$archived:ident| $native:expr $(, |$value:ident| $fits:expr)?);* $(;)*) => {
+        $(
+            impl private::Sealed for $t {}
+
+            impl ArchivedOrdinalInt for $t {
+                #[inline]
+                fn from_archived($archived: &Archived<Self>) -> Self {
+                    $native
+                }
+
+                $(
+                    #[inline]
+                    fn fits_archived($value: Self) -> bool {
+                        $fits
+                    }
+                )?
+            }
+        )*
+    };
+}
+
+impl_archived_ordinal_int!(
+    i8 => |archived| *archived;
+    u8 => |archived| *archived;
+    i16 => |archived| archived.to_native();
+    i32 => |archived| archived.to_native();
+    i64 => |archived| archived.to_native();
+    i128 => |archived| archived.to_native();
+    isize => |archived| archived.to_native() as isize, |value| FixedIsize::try_from(value).is_ok();
+    u16 => |archived| archived.to_native();
+    u32 => |archived| archived.to_native();
+    u64 => |archived| archived.to_native();
+    u128 => |archived| archived.to_native();
+    usize => |archived| archived.to_native() as usize, |value| FixedUsize::try_from(value).is_ok();
+);
+
+/// The archived form of an ordinalized enum, which is the archived value of the variant.
+///
+/// `usize` and `isize` values are archived with the pointer width of `rkyv`, so serializing a variant whose value does not fit fails with [`UnarchivableOrdinalError`].
+#[repr(transparent)]
+pub struct ArchivedOrdinal<E: Ordinalize>
+where
+    E::VariantType: ArchivedOrdinalInt, {
+    value:   Archived<E::VariantType>,
+    _marker: PhantomData<fn() -> E>,
+}
+
+impl<E: Ordinalize> ArchivedOrdinal<E>
+where
+    E::VariantType: ArchivedOrdinalInt,
+{
+    /// Retrieve the archived value.
+    #[inline]
+    pub fn ordinal(&self) -> E::VariantType {
+        E::VariantType::from_archived(&self.value)
+    }
+
+    /// Obtain the archived variant. `None` is returned only if the archive has been accessed without validation and the value is corrupt.
+    #[inline]
+    pub fn get(&self) -> Option<E> {
+        E::from_ordinal(self.ordinal())
+    }
+
+    /// Write the archived form of a variant. It is used by the implementation of `Archive::resolve`.
+    #[inline]
+    pub fn resolve_from(variant: &E, out: Place<Self>) {
+        // `ArchivedOrdinal` is a transparent wrapper of the archived value
+        let out = unsafe { out.cast_unchecked::<Archived<E::VariantType>>() };
+
+        variant.ordinal().resolve((), out);
+    }
+
+    /// Check that the value of a variant fits in its archived form. It is used by the implementation of `Serialize::serialize` for the enums whose `VariantType` is `usize` or `isize`.
+    #[inline]
+    pub fn serialize_from<S: Fallible + ?Sized>(variant: &E) -> Result<(), S::Error>
+    where
+        S::Error: Source, {
+        let number = variant.ordinal();
+
+        if !E::VariantType::fits_archived(number) {
+            fail!(UnarchivableOrdinalError::<E>::new(number));
+        }
+
+        Ok(())
+    }
+}
+
+impl<E: Ordinalize> Debug for ArchivedOrdinal<E>
+where
+    E::VariantType: ArchivedOrdinalInt,
+{
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("ArchivedOrdinal").field(&self.ordinal()).finish()
+    }
+}
+
+impl<E: Ordinalize> PartialEq for ArchivedOrdinal<E>
+where
+    E::VariantType: ArchivedOrdinalInt,
+{
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.ordinal() == other.ordinal()
+    }
+}
+
+impl<E: Ordinalize> Eq for ArchivedOrdinal<E> where E::VariantType: ArchivedOrdinalInt {}
+
+impl<E: Ordinalize> PartialEq<E> for ArchivedOrdinal<E>
+where
+    E::VariantType: ArchivedOrdinalInt,
+{
+    #[inline]
+    fn eq(&self, other: &E) -> bool {
+        self.ordinal() == other.ordinal()
+    }
+}
+
+unsafe impl<E: Ordinalize> Portable for ArchivedOrdinal<E> where E::VariantType: ArchivedOrdinalInt {}
+
+unsafe impl<E: Ordinalize, C: Fallible + ?Sized> CheckBytes<C> for ArchivedOrdinal<E>
+where
+    E::VariantType: ArchivedOrdinalInt,
+    Archived<E::VariantType>: CheckBytes<C>,
+    C::Error: Source,
+{
+    #[inline]
+    unsafe fn check_bytes(value: *const Self, context: &mut C) -> Result<(), C::Error> {
+        let value = value.cast::<Archived<E::VariantType>>();
+
+        unsafe { Archived::<E::VariantType>::check_bytes(value, context)? };
+
+        let number = E::VariantType::from_archived(unsafe { &*value });
+
+        if E::from_ordinal(number).is_none() {
+            fail!(UnknownOrdinalError::<E>::new(number));
+        }
+
+        Ok(())
+    }
+}
+
+impl<E: Ordinalize, D: Fallible + ?Sized> Deserialize<E, D> for ArchivedOrdinal<E>
+where
+    E: Archive<Archived = Self>,
+    E::VariantType: ArchivedOrdinalInt,
+    D::Error: Source,
+{
+    #[inline]
+    fn deserialize(&self, _deserializer: &mut D) -> Result<E, D::Error> {
+        let number = self.ordinal();
+
+        match E::from_ordinal(number) {
+            Some(variant) => Ok(variant),
+            None => fail!(UnknownOrdinalError::<E>::new(number)),
+        }
+    }
+}
+
+/// An error which indicates that the value of a variant of `E` does not fit in its archived form.
+pub struct UnarchivableOrdinalError<E: Ordinalize> {
+    value:   E::VariantType,
+    _marker: PhantomData<fn() -> E>,
+}
+
+impl<E: Ordinalize> UnarchivableOrdinalError<E> {
+    /// Create an error for a value which does not fit in its archived form.
+    #[inline]
+    pub fn new(value: E::VariantType) -> Self {
+        Self {
+            value,
+            _marker: PhantomData,
+        }
+    }
+
+    /// The value which does not fit in its archived form.
+    #[inline]
+    pub fn value(&self) -> E::VariantType {
+        self.value
+    }
+}
+
+impl<E: Ordinalize> Clone for UnarchivableOrdinalError<E> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<E: Ordinalize> Copy for UnarchivableOrdinalError<E> {}
+
+impl<E: Ordinalize> PartialEq for UnarchivableOrdinalError<E> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl<E: Ordinalize> Eq for UnarchivableOrdinalError<E> {}
+
+impl<E: Ordinalize> Debug for UnarchivableOrdinalError<E> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("UnarchivableOrdinalError").field("value", &self.value).finish()
+    }
+}
+
+impl<E: Ordinalize> Display for UnarchivableOrdinalError<E> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "`{}` of `{}` does not fit in the archived `{}`",
+            self.value,
+            type_name::<E>(),
+            type_name::<E::VariantType>()
+        )
+    }
+}
+
+impl<E: Ordinalize> core::error::Error for UnarchivableOrdinalError<E> {}
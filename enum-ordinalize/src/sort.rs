@@ -0,0 +1,311 @@
+use alloc::{boxed::Box, vec, vec::Vec};
+use core::{
+    fmt::{self, Debug, Formatter},
+    iter::FusedIterator,
+    marker::PhantomData,
+};
+
+use crate::Ordinalize;
+
+/// Sort a slice of variants in declaration order.
+///
+/// It is a stable counting sort, which takes `O(n + k)` time and extra space for `k = VARIANT_COUNT` buckets. See [the crate documentation](crate#alloc) for the time with a manual `Ordinalize` implementation.
+///
+/// ```rust
+/// # #[cfg(feature = "derive")]
+/// # {
+/// use enum_ordinalize::Ordinalize;
+///
+/// #[derive(Debug, PartialEq, Eq, Ordinalize)]
+/// enum Kind {
+///     Click  = 2,
+///     Scroll = 1,
+/// }
+///
+/// let mut kinds = [Kind::Scroll, Kind::Click, Kind::Scroll];
+///
+/// enum_ordinalize::sort_by_variant(&mut kinds);
+///
+/// assert_eq!([Kind::Click, Kind::Scroll, Kind::Scroll], kinds);
+/// # }
+/// ```
+#[inline]
+pub fn sort_by_variant<E: Ordinalize>(slice: &mut [E]) {
+    counting_sort(slice, E::VARIANT_COUNT, |value| value.variant_index());
+}
+
+/// Sort a slice in declaration order of the variants which `f` returns for its elements. The order of the elements with the same variant is preserved.
+///
+/// It is a counting sort, which calls `f` once for each element and takes `O(n + k)` time and extra space for `k = VARIANT_COUNT` buckets. See [the crate documentation](crate#alloc) for the time with a manual `Ordinalize` implementation.
+#[inline]
+pub fn sort_by_key_variant<T, E: Ordinalize, F: FnMut(&T) -> E>(slice: &mut [T], mut f: F) {
+    counting_sort(slice, E::VARIANT_COUNT, |element| f(element).variant_index());
+}
+
+/// Sort a slice of variants in ascending order of their values.
+///
+/// It is a stable counting sort, which takes `O(n + k)` time and extra space for `k = VARIANT_COUNT` buckets. See [the crate documentation](crate#alloc) for the time with a manual `Ordinalize` implementation. Ranking the values takes another `O(k log k)` time.
+#[inline]
+pub fn sort_by_variant_value<E: Ordinalize>(slice: &mut [E]) {
+    let ranks = value_ranks::<E>();
+
+    counting_sort(slice, E::VARIANT_COUNT, |value| ranks[value.variant_index()]);
+}
+
+/// Sort a slice in ascending order of the values of the variants which `f` returns for its elements. The order of the elements with the same variant is preserved.
+///
+/// It is a counting sort, which calls `f` once for each element and takes `O(n + k)` time and extra space for `k = VARIANT_COUNT` buckets. See [the crate documentation](crate#alloc) for the time with a manual `Ordinalize` implementation. Ranking the values takes another `O(k log k)` time.
+#[inline]
+pub fn sort_by_key_variant_value<T, E: Ordinalize, F: FnMut(&T) -> E>(slice: &mut [T], mut f: F) {
+    let ranks = value_ranks::<E>();
+
+    counting_sort(slice, E::VARIANT_COUNT, |element| ranks[f(element).variant_index()]);
+}
+
+/// Group the elements of a slice by the variants which `f` returns for them.
+///
+/// The slice is sorted in place like [`sort_by_key_variant`], and the returned [`VariantGroups`] borrows it to provide the group of each variant as a subslice.
+///
+/// [`VariantGroups`] works like a map from each variant to the subslice of its elements.
+///
+/// ```rust
+/// # #[cfg(feature = "derive")]
+/// # {
+/// use enum_ordinalize::Ordinalize;
+///
+/// #[derive(Debug, Clone, Copy, PartialEq, Eq, Ordinalize)]
+/// enum Kind {
+///     Click,
+///     Scroll,
+///     Resize,
+/// }
+///
+/// let mut events = [(Kind::Scroll, 1), (Kind::Click, 2), (Kind::Scroll, 3)];
+///
+/// let groups =
+///     enum_ordinalize::group_by_variant(&mut events, |event| event.0);
+///
+/// assert_eq!(
+///     [(Kind::Scroll, 1), (Kind::Scroll, 3)],
+///     groups.get(&Kind::Scroll)
+/// );
+/// assert!(groups.get(&Kind::Resize).is_empty());
+/// # }
+/// ```
+#[inline]
+pub fn group_by_variant<T, E: Ordinalize, F: FnMut(&T) -> E>(
+    slice: &mut [T],
+    mut f: F,
+) -> VariantGroups<'_, E, T> {
+    let bounds = counting_sort(slice, E::VARIANT_COUNT, |element| f(element).variant_index());
+
+    VariantGroups {
+        slice,
+        bounds,
+        _marker: PhantomData,
+    }
+}
+
+/// Return the rank of the value of each variant among `VALUES`, by the index of the variant.
+fn value_ranks<E: Ordinalize>() -> Vec<usize> {
+    let mut indexes: Vec<usize> = (0..E::VARIANT_COUNT).collect();
+
+    indexes.sort_by_key(|index| E::VALUES[*index]);
+
+    let mut ranks = vec![0; E::VARIANT_COUNT];
+
+    for (rank, index) in indexes.into_iter().enumerate() {
+        ranks[index] = rank;
+    }
+
+    ranks
+}
+
+/// Stably sort a slice by the buckets of its elements and return the bounds of the buckets, whose length is `buckets + 1`.
+fn counting_sort<T>(
+    slice: &mut [T],
+    buckets: usize,
+    mut bucket_of: impl FnMut(&T) -> usize,
+) -> Box<[usize]> {
+    let element_buckets: Vec<usize> = slice.iter().map(&mut bucket_of).collect();
+
+    let mut bounds = vec![0; buckets + 1];
+
+    for bucket in element_buckets.iter().copied() {
+        bounds[bucket + 1] += 1;
+    }
+
+    for bucket in 0..buckets {
+        bounds[bucket + 1] += bounds[bucket];
+    }
+
+    let mut next = bounds.clone();
+
+    let mut destinations: Vec<usize> = element_buckets
+        .into_iter()
+        .map(|bucket| {
+            let destination = next[bucket];
+
+            next[bucket] += 1;
+
+            destination
+        })
+        .collect();
+
+    // move every element to its destination by following the cycles of the permutation
+    for index in 0..slice.len() {
+        while destinations[index] != index {
+            let destination = destinations[index];
+
+            slice.swap(index, destination);
+            destinations.swap(index, destination);
+        }
+    }
+
+    bounds.into_boxed_slice()
+}
+
+/// The elements of a slice grouped by variants.
+///
+/// It is created by the [`group_by_variant`] function.
+pub struct VariantGroups<'a, E: Ordinalize, T> {
+    slice:   &'a mut [T],
+    bounds:  Box<[usize]>,
+    _marker: PhantomData<fn() -> E>,
+}
+
+impl<'a, E: Ordinalize, T> VariantGroups<'a, E, T> {
+    /// Return the elements of a variant.
+    #[inline]
+    pub fn get(&self, value: &E) -> &[T] {
+        let index = value.variant_index();
+
+        &self.slice[self.bounds[index]..self.bounds[index + 1]]
+    }
+
+    /// Return the elements of a variant mutably.
+    #[inline]
+    pub fn get_mut(&mut self, value: &E) -> &mut [T] {
+        let index = value.variant_index();
+
+        &mut self.slice[self.bounds[index]..self.bounds[index + 1]]
+    }
+
+    /// Return the whole slice, which is sorted in declaration order of the variants.
+    #[inline]
+    pub fn into_slice(self) -> &'a mut [T] {
+        self.slice
+    }
+
+    /// Iterate over the variants and their elements, in declaration order.
+    #[inline]
+    pub fn iter(&self) -> VariantGroupsIter<'_, E, T> {
+        VariantGroupsIter {
+            slice:   &*self.slice,
+            bounds:  &self.bounds,
+            front:   0,
+            back:    E::VARIANT_COUNT,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<'a, E: Ordinalize + Debug, T: Debug> Debug for VariantGroups<'a, E, T> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<'a, 'b, E: Ordinalize, T> IntoIterator for &'b VariantGroups<'a, E, T> {
+    type IntoIter = VariantGroupsIter<'b, E, T>;
+    type Item = (E, &'b [T]);
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// An iterator over the variants and their elements of a [`VariantGroups`].
+pub struct VariantGroupsIter<'a, E: Ordinalize, T> {
+    slice:   &'a [T],
+    bounds:  &'a [usize],
+    front:   usize,
+    back:    usize,
+    _marker: PhantomData<fn() -> E>,
+}
+
+impl<'a, E: Ordinalize, T> VariantGroupsIter<'a, E, T> {
+    #[inline]
+    fn group(&self, index: usize) -> Option<(E, &'a [T])> {
+        Some((
+            E::from_variant_index(index)?,
+            &self.slice[self.bounds[index]..self.bounds[index + 1]],
+        ))
+    }
+}
+
+impl<'a, E: Ordinalize, T> Clone for VariantGroupsIter<'a, E, T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            slice:   self.slice,
+            bounds:  self.bounds,
+            front:   self.front,
+            back:    self.back,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<'a, E: Ordinalize, T> Debug for VariantGroupsIter<'a, E, T> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("VariantGroupsIter")
+            .field("front", &self.front)
+            .field("back", &self.back)
+            .finish()
+    }
+}
+
+impl<'a, E: Ordinalize, T> Iterator for VariantGroupsIter<'a, E, T> {
+    type Item = (E, &'a [T]);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front < self.back {
+            let index = self.front;
+
+            self.front += 1;
+
+            self.group(index)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.back - self.front;
+
+        (len, Some(len))
+    }
+}
+
+impl<'a, E: Ordinalize, T> DoubleEndedIterator for VariantGroupsIter<'a, E, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front < self.back {
+            self.back -= 1;
+
+            self.group(self.back)
+        } else {
+            None
+        }
+    }
+}
+
+impl<'a, E: Ordinalize, T> ExactSizeIterator for VariantGroupsIter<'a, E, T> {}
+
+impl<'a, E: Ordinalize, T> FusedIterator for VariantGroupsIter<'a, E, T> {}
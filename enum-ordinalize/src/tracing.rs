@@ -0,0 +1,44 @@
+/*!
+Recording ordinalized enums with [`tracing`](https://crates.io/crates/tracing).
+
+`tracing::Value` is sealed, so it cannot be implemented for enums. Instead, a variant is recorded as two fields, its name (a `&'static str`) and its value (an integer), both of which are `tracing::Value`s. In events, use the methods of [`VariantNames`] and [`Ordinalize`](crate::Ordinalize) directly. For spans, whose fields are recorded later, [`record`] fills both fields.
+
+```rust
+# #[cfg(feature = "derive")]
+# {
+use enum_ordinalize::{tracing::record, Ordinalize, VariantNames};
+use tracing::field::Empty;
+
+#[derive(Ordinalize)]
+enum Kind {
+    Header = 1,
+    Body = 2,
+}
+
+let kind = Kind::Body;
+
+tracing::info!(kind = kind.variant_name(), kind.ordinal = kind.ordinal(), "received");
+
+let span = tracing::info_span!("request", kind = Empty, kind.ordinal = Empty);
+
+record(&span, "kind", &kind);
+# }
+```
+*/
+
+use alloc::format;
+
+use ::tracing::{field::Value, Span};
+
+use crate::VariantNames;
+
+/// Record the name of a variant in the field `field` of a span, and its value in the field `{field}.ordinal`.
+///
+/// As with `Span::record`, fields which have not been declared when the span was created are ignored.
+#[inline]
+pub fn record<E: VariantNames>(span: &Span, field: &str, variant: &E)
+where
+    E::VariantType: Value, {
+    span.record(field, variant.variant_name());
+    span.record(format!("{field}.ordinal").as_str(), variant.ordinal());
+}
@@ -1,3 +1,11 @@
+use core::ops::RangeBounds;
+#[cfg(feature = "std")]
+use std::io;
+
+use crate::{bits::index_bits, OrdinalInt, VariantRange};
+#[cfg(feature = "std")]
+use crate::{Endianness, UnknownOrdinalError};
+
 /// This trait provides an enum with the ability to not only obtain the ordinal values of its variants but also allows for the construction of enums from an ordinal value.
 ///
 /// ```rust
@@ -41,7 +49,7 @@
 /// ```
 pub trait Ordinalize: Sized + 'static {
     /// The type of the values of the variants.
-    type VariantType;
+    type VariantType: OrdinalInt;
 
     /// The count of variants.
     const VARIANT_COUNT: usize;
@@ -52,6 +60,11 @@ pub trait Ordinalize: Sized + 'static {
     /// List of values for all variants of this enum.
     const VALUES: &'static [Self::VariantType];
 
+    /// The minimum number of bits to encode every variant as a bit field, which is at least 1 and at most 64.
+    ///
+    /// By default, a variant is encoded as its index in `VARIANTS`. The derive macro and the `ordinalize!` macro encode a variant as its value instead, in two's complement if any value is negative, unless the `bits = "index"` attribute is used, or the derive macro finds that the values need more than 64 bits. Then `into_bits` and `from_bits` are implemented accordingly.
+    const BITS: u32 = index_bits(Self::VARIANT_COUNT);
+
     /// Obtain a variant based on an integer number.
     ///
     /// # Safety
@@ -65,4 +78,211 @@ pub trait Ordinalize: Sized + 'static {
 
     /// Retrieve the integer number of this variant.
     fn ordinal(&self) -> Self::VariantType;
+
+    /// Obtain a variant based on an integer number of any integer type.
+    ///
+    /// The number is converted through `i128` without truncation, so a number out of the range of `Self::VariantType` results in `None`. Numbers greater than `i128::MAX` are not supported.
+    #[inline]
+    fn from_ordinal_any<T: TryInto<i128>>(number: T) -> Option<Self> {
+        let number = number.try_into().ok()?;
+
+        Self::from_ordinal(Self::VariantType::try_from_i128(number)?)
+    }
+
+    /// Retrieve the integer number of this variant as any integer type.
+    ///
+    /// The number is converted through `i128` without truncation, so `None` is returned if it cannot be represented by `T`.
+    #[inline]
+    fn ordinal_as<T: TryFrom<i128>>(&self) -> Option<T> {
+        let number = self.ordinal().to_i128()?;
+
+        T::try_from(number).ok()
+    }
+
+    /// Retrieve the index of this variant in `VARIANTS`.
+    #[inline]
+    fn variant_index(&self) -> usize {
+        let ordinal = self.ordinal();
+
+        Self::VALUES
+            .iter()
+            .position(|value| *value == ordinal)
+            .expect("the ordinal of a variant should be in `VALUES`")
+    }
+
+    /// Obtain a variant based on its index in `VARIANTS`.
+    #[inline]
+    fn from_variant_index(index: usize) -> Option<Self> {
+        Self::VALUES.get(index).and_then(|value| Self::from_ordinal(*value))
+    }
+
+    /// Iterate over the variants between two bounds, in declaration order.
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "derive")]
+    /// # {
+    /// use enum_ordinalize::Ordinalize;
+    ///
+    /// #[derive(Debug, PartialEq, Eq, Ordinalize)]
+    /// enum Level {
+    ///     Trace,
+    ///     Debug,
+    ///     Info,
+    ///     Warn,
+    ///     Error,
+    /// }
+    ///
+    /// assert_eq!(
+    ///     vec![Level::Debug, Level::Info],
+    ///     Level::range(Level::Debug..Level::Warn).collect::<Vec<_>>()
+    /// );
+    /// assert_eq!(
+    ///     vec![Level::Warn, Level::Error],
+    ///     Level::range(Level::Warn..).collect::<Vec<_>>()
+    /// );
+    /// # }
+    /// ```
+    #[inline]
+    fn range<R: RangeBounds<Self>>(range: R) -> VariantRange<Self> {
+        VariantRange::new(range)
+    }
+
+    /// Return the memory representation of the ordinal of this variant as a byte array in little-endian byte order.
+    #[inline]
+    fn to_le_bytes(&self) -> <Self::VariantType as OrdinalInt>::Bytes {
+        self.ordinal().to_le_bytes()
+    }
+
+    /// Return the memory representation of the ordinal of this variant as a byte array in big-endian byte order.
+    #[inline]
+    fn to_be_bytes(&self) -> <Self::VariantType as OrdinalInt>::Bytes {
+        self.ordinal().to_be_bytes()
+    }
+
+    /// Return the memory representation of the ordinal of this variant as a byte array in native byte order.
+    #[inline]
+    fn to_ne_bytes(&self) -> <Self::VariantType as OrdinalInt>::Bytes {
+        self.ordinal().to_ne_bytes()
+    }
+
+    /// Obtain a variant based on the representation of its ordinal as a byte array in little endian.
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "derive")]
+    /// # {
+    /// use enum_ordinalize::Ordinalize;
+    ///
+    /// #[derive(Debug, PartialEq, Eq, Ordinalize)]
+    /// #[repr(u16)]
+    /// enum Kind {
+    ///     Header = 0x0102,
+    ///     Body   = 0x0304,
+    /// }
+    ///
+    /// assert_eq!([0x02, 0x01], Kind::Header.to_le_bytes());
+    /// assert_eq!(Some(Kind::Body), Kind::from_le_bytes([0x04, 0x03]));
+    /// assert_eq!(None, Kind::from_le_bytes([0x03, 0x04]));
+    /// # }
+    /// ```
+    #[inline]
+    fn from_le_bytes(bytes: <Self::VariantType as OrdinalInt>::Bytes) -> Option<Self> {
+        Self::from_ordinal(Self::VariantType::from_le_bytes(bytes))
+    }
+
+    /// Obtain a variant based on the representation of its ordinal as a byte array in big endian.
+    #[inline]
+    fn from_be_bytes(bytes: <Self::VariantType as OrdinalInt>::Bytes) -> Option<Self> {
+        Self::from_ordinal(Self::VariantType::from_be_bytes(bytes))
+    }
+
+    /// Obtain a variant based on the representation of its ordinal as a byte array in native endianness.
+    #[inline]
+    fn from_ne_bytes(bytes: <Self::VariantType as OrdinalInt>::Bytes) -> Option<Self> {
+        Self::from_ordinal(Self::VariantType::from_ne_bytes(bytes))
+    }
+
+    /// Encode this variant in the lowest `BITS` bits.
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "derive")]
+    /// # {
+    /// use enum_ordinalize::Ordinalize;
+    ///
+    /// #[derive(Debug, PartialEq, Eq, Ordinalize)]
+    /// #[repr(i8)]
+    /// enum Offset {
+    ///     Back    = -2,
+    ///     Stay    = 0,
+    ///     Forward = 1,
+    /// }
+    ///
+    /// assert_eq!(2, Offset::BITS);
+    /// assert_eq!(0b10, Offset::Back.into_bits());
+    /// assert_eq!(Some(Offset::Forward), Offset::from_bits(0b01));
+    /// assert_eq!(None, Offset::from_bits(0b11));
+    /// # }
+    /// ```
+    #[allow(clippy::wrong_self_convention)]
+    #[inline]
+    fn into_bits(&self) -> u64 {
+        self.variant_index() as u64
+    }
+
+    /// Obtain a variant based on its encoding in the lowest `BITS` bits. `None` is returned if any higher bit is set or the bits do not correspond to any variant.
+    #[inline]
+    fn from_bits(bits: u64) -> Option<Self> {
+        if Self::BITS < u64::BITS && bits >> Self::BITS != 0 {
+            return None;
+        }
+
+        Self::from_variant_index(usize::try_from(bits).ok()?)
+    }
+
+    /// Read an ordinal from a reader in the byte order `En` and obtain the corresponding variant.
+    ///
+    /// Exactly as many bytes as the size of `Self::VariantType` are read. If the ordinal does not correspond to any variant, an error of the [`io::ErrorKind::InvalidData`] kind is returned, which wraps an [`UnknownOrdinalError`].
+    ///
+    /// ```rust
+    /// # #[cfg(all(feature = "derive", feature = "std"))]
+    /// # {
+    /// use enum_ordinalize::{BigEndian, Ordinalize};
+    ///
+    /// #[derive(Debug, PartialEq, Eq, Ordinalize)]
+    /// #[repr(u16)]
+    /// enum Kind {
+    ///     Header = 0x0102,
+    ///     Body   = 0x0304,
+    /// }
+    ///
+    /// let mut data: &[u8] = &[0x03, 0x04, 0x01, 0x02, 0xFF, 0xFF];
+    ///
+    /// assert_eq!(Kind::Body, Kind::read_from::<BigEndian, _>(&mut data).unwrap());
+    /// assert_eq!(
+    ///     Kind::Header,
+    ///     Kind::read_from::<BigEndian, _>(&mut data).unwrap()
+    /// );
+    /// assert!(Kind::read_from::<BigEndian, _>(&mut data).is_err());
+    /// # }
+    /// ```
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    fn read_from<En: Endianness, R: io::Read + ?Sized>(reader: &mut R) -> io::Result<Self> {
+        let mut bytes = <Self::VariantType as OrdinalInt>::Bytes::default();
+
+        reader.read_exact(bytes.as_mut())?;
+
+        let number = En::from_bytes::<Self::VariantType>(bytes);
+
+        Self::from_ordinal(number).ok_or_else(|| {
+            io::Error::new(io::ErrorKind::InvalidData, UnknownOrdinalError::<Self>::new(number))
+        })
+    }
+
+    /// Write the ordinal of this variant to a writer in the byte order `En`.
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    #[inline]
+    fn write_to<En: Endianness, W: io::Write + ?Sized>(&self, writer: &mut W) -> io::Result<()> {
+        writer.write_all(En::to_bytes(self.ordinal()).as_ref())
+    }
 }
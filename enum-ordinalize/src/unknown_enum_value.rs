@@ -0,0 +1,17 @@
+use core::fmt::{self, Display, Formatter};
+
+/// An error which indicates that an `i32` number is not the value of any variant of a protobuf-compatible enum.
+///
+/// It is returned by the `TryFrom<i32>` implementation generated with the `protobuf` attribute, like the one of `prost`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct UnknownEnumValue(pub i32);
+
+impl Display for UnknownEnumValue {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown enumeration value {}", self.0)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for UnknownEnumValue {}
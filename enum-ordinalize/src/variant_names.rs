@@ -0,0 +1,53 @@
+use crate::Ordinalize;
+
+/// This trait provides the names of an ordinalized enum's variants, which are the identifiers of the variants.
+///
+/// It is implemented by `#[derive(Ordinalize)]` and the `ordinalize!` macro, along with the `Ordinalize` trait.
+///
+/// ```rust
+/// # #[cfg(feature = "derive")]
+/// # {
+/// use enum_ordinalize::{Ordinalize, VariantNames};
+///
+/// #[derive(Debug, PartialEq, Eq, Ordinalize)]
+/// enum Level {
+///     Info = 2,
+///     Warn = 1,
+/// }
+///
+/// assert_eq!(["Info", "Warn"], Level::VARIANT_NAMES);
+/// assert_eq!("Warn", Level::Warn.variant_name());
+/// assert_eq!(Some(Level::Info), Level::from_variant_name("Info"));
+/// assert_eq!(None, Level::from_variant_name("Error"));
+/// # }
+/// ```
+pub trait VariantNames: Ordinalize {
+    /// List of names for all variants of this enum, in declaration order.
+    const VARIANT_NAMES: &'static [&'static str];
+
+    /// Retrieve the name of this variant.
+    #[inline]
+    fn variant_name(&self) -> &'static str {
+        Self::VARIANT_NAMES[self.variant_index()]
+    }
+
+    /// Obtain a variant based on its name. The name is matched case-sensitively.
+    #[inline]
+    fn from_variant_name(name: &str) -> Option<Self> {
+        let index = Self::VARIANT_NAMES.iter().position(|variant_name| *variant_name == name)?;
+
+        Self::from_variant_index(index)
+    }
+}
+
+/// Remove the `r#` prefix of a raw identifier, as the derive macro does to the names of the variants.
+#[doc(hidden)]
+pub const fn unraw(name: &'static str) -> &'static str {
+    match name.as_bytes() {
+        [b'r', b'#', rest @ ..] => match core::str::from_utf8(rest) {
+            Ok(name) => name,
+            Err(_) => name,
+        },
+        _ => name,
+    }
+}
@@ -0,0 +1,85 @@
+#![cfg(all(feature = "derive", feature = "arbitrary"))]
+
+use arbitrary::{Arbitrary, Unstructured};
+use enum_ordinalize::Ordinalize;
+
+#[test]
+fn arbitrary() {
+    #[derive(Debug, PartialEq, Eq, Ordinalize)]
+    #[ordinalize(arbitrary)]
+    enum MyEnum {
+        A = 10,
+        B = 20,
+        C = 30,
+    }
+
+    let bytes: Vec<u8> = (0..=255).collect();
+    let mut u = Unstructured::new(&bytes);
+
+    let mut counts = [0usize; 3];
+
+    while !u.is_empty() {
+        let variant = MyEnum::arbitrary(&mut u).unwrap();
+
+        counts[variant.variant_index()] += 1;
+    }
+
+    assert!(counts.iter().all(|count| *count > 0), "{counts:?}");
+}
+
+#[test]
+fn size_hint() {
+    #[derive(Debug, PartialEq, Eq, Ordinalize)]
+    #[ordinalize(arbitrary)]
+    enum One {
+        A,
+    }
+
+    #[derive(Debug, PartialEq, Eq, Ordinalize)]
+    #[ordinalize(arbitrary)]
+    #[repr(u16)]
+    enum Many {
+        A = 1000,
+        B = 2000,
+    }
+
+    assert_eq!((0, Some(0)), One::size_hint(0));
+    assert_eq!((1, Some(1)), Many::size_hint(0));
+
+    let mut u = Unstructured::new(&[1, 0xFF]);
+
+    assert_eq!(Many::B, Many::arbitrary(&mut u).unwrap());
+    assert_eq!(1, u.len());
+}
+
+#[test]
+fn own_arbitrary() {
+    // without the `arbitrary` attribute, no implementation is generated to conflict with this one
+    #[derive(Debug, PartialEq, Eq, Ordinalize)]
+    enum MyEnum {
+        A,
+        B,
+    }
+
+    impl<'a> Arbitrary<'a> for MyEnum {
+        fn arbitrary(_u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+            Ok(MyEnum::B)
+        }
+    }
+
+    assert_eq!(MyEnum::B, MyEnum::arbitrary(&mut Unstructured::new(&[])).unwrap());
+}
+
+#[test]
+fn generic() {
+    #[derive(Debug, PartialEq, Eq, Ordinalize)]
+    #[ordinalize(arbitrary)]
+    enum Slot<const N: usize> {
+        A,
+        B,
+    }
+
+    let mut u = Unstructured::new(&[1]);
+
+    assert_eq!(Slot::<4>::B, Slot::<4>::arbitrary(&mut u).unwrap());
+}
@@ -0,0 +1,76 @@
+#![cfg(all(feature = "derive", feature = "traits"))]
+
+use std::{
+    sync::{atomic::Ordering, Arc},
+    thread,
+};
+
+use enum_ordinalize::{AtomicEnum, Ordinalize};
+
+#[derive(Debug, Default, PartialEq, Eq, Ordinalize)]
+#[repr(i16)]
+enum Phase {
+    #[default]
+    Idle   = -1,
+    First  = 100,
+    Second = 200,
+    Third  = 300,
+}
+
+#[test]
+fn load_store_swap() {
+    let phase = AtomicEnum::<Phase>::default();
+
+    assert_eq!(Phase::Idle, phase.load(Ordering::Relaxed));
+
+    phase.store(Phase::Second, Ordering::Relaxed);
+
+    assert_eq!(Phase::Second, phase.swap(Phase::Third, Ordering::Relaxed));
+    assert_eq!("Third", format!("{phase:?}"));
+    assert_eq!(Phase::Third, phase.into_inner());
+}
+
+#[test]
+fn compare_exchange() {
+    let phase = AtomicEnum::from(Phase::First);
+
+    assert_eq!(
+        Err(Phase::First),
+        phase.compare_exchange(Phase::Idle, Phase::Second, Ordering::AcqRel, Ordering::Acquire)
+    );
+    assert_eq!(
+        Ok(Phase::First),
+        phase.compare_exchange(Phase::First, Phase::Second, Ordering::AcqRel, Ordering::Acquire)
+    );
+    assert_eq!(Phase::Second, phase.load(Ordering::Acquire));
+}
+
+#[test]
+fn fetch_update() {
+    let phase = Arc::new(AtomicEnum::new(Phase::Idle));
+
+    let handles: Vec<_> = (0..3)
+        .map(|_| {
+            let phase = Arc::clone(&phase);
+
+            thread::spawn(move || {
+                phase.fetch_update(Ordering::AcqRel, Ordering::Acquire, |phase| match phase {
+                    Phase::Idle => Some(Phase::First),
+                    Phase::First => Some(Phase::Second),
+                    Phase::Second => Some(Phase::Third),
+                    Phase::Third => None,
+                })
+            })
+        })
+        .collect();
+
+    for handle in handles {
+        assert!(handle.join().unwrap().is_ok());
+    }
+
+    assert_eq!(Phase::Third, phase.load(Ordering::Acquire));
+    assert_eq!(
+        Err(Phase::Third),
+        phase.fetch_update(Ordering::AcqRel, Ordering::Acquire, |_| None)
+    );
+}
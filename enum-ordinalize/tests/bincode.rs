@@ -0,0 +1,118 @@
+#![cfg(all(feature = "derive", feature = "bincode"))]
+
+use bincode::{
+    config,
+    error::{AllowedEnumVariants, DecodeError},
+};
+use enum_ordinalize::Ordinalize;
+
+#[derive(Debug, PartialEq, Eq, Ordinalize)]
+#[ordinalize(bincode)]
+#[repr(i32)]
+enum Kind {
+    Header = 1000,
+    Body   = -2,
+}
+
+#[derive(Debug, PartialEq, Eq, Ordinalize)]
+#[ordinalize(bincode, encode = "index")]
+#[repr(i32)]
+enum IndexedKind {
+    Header = 1000,
+    Body   = -2,
+}
+
+#[test]
+fn value() {
+    let config = config::standard().with_fixed_int_encoding().with_big_endian();
+
+    let bytes = bincode::encode_to_vec(Kind::Header, config).unwrap();
+
+    assert_eq!(vec![0x00, 0x00, 0x03, 0xE8], bytes);
+    assert_eq!((Kind::Header, 4), bincode::decode_from_slice(&bytes, config).unwrap());
+
+    let bytes = bincode::encode_to_vec(Kind::Body, config::standard()).unwrap();
+
+    assert_eq!((Kind::Body, 1), bincode::decode_from_slice(&bytes, config::standard()).unwrap());
+
+    assert!(matches!(
+        bincode::decode_from_slice::<Kind, _>(&[0, 0, 0, 0], config),
+        Err(DecodeError::Other(_))
+    ));
+}
+
+#[test]
+fn index() {
+    let bytes = bincode::encode_to_vec(IndexedKind::Body, config::standard()).unwrap();
+
+    assert_eq!(vec![1], bytes);
+    assert_eq!(
+        (IndexedKind::Body, 1),
+        bincode::decode_from_slice(&bytes, config::standard()).unwrap()
+    );
+
+    match bincode::decode_from_slice::<IndexedKind, _>(&[2], config::standard()) {
+        Err(DecodeError::UnexpectedVariant {
+            allowed,
+            found,
+            ..
+        }) => {
+            assert_eq!(
+                &AllowedEnumVariants::Range {
+                    min: 0, max: 1
+                },
+                allowed
+            );
+            assert_eq!(2, found);
+        },
+        result => panic!("{result:?}"),
+    }
+}
+
+#[test]
+fn borrow_decode() {
+    let bytes = bincode::encode_to_vec(Kind::Body, config::standard()).unwrap();
+
+    let (kind, _): (Kind, usize) =
+        bincode::borrow_decode_from_slice(&bytes, config::standard()).unwrap();
+
+    assert_eq!(Kind::Body, kind);
+}
+
+#[test]
+fn own_implementation() {
+    // without the `bincode` attribute, the enum can implement the traits of bincode by itself
+    #[derive(Debug, PartialEq, Eq, Ordinalize)]
+    enum MyEnum {
+        A = 7,
+    }
+
+    impl bincode::Encode for MyEnum {
+        fn encode<E: bincode::enc::Encoder>(
+            &self,
+            encoder: &mut E,
+        ) -> Result<(), bincode::error::EncodeError> {
+            bincode::Encode::encode(&(self.ordinal() as u8 + 1), encoder)
+        }
+    }
+
+    assert_eq!(vec![8], bincode::encode_to_vec(MyEnum::A, config::standard()).unwrap());
+}
+
+#[test]
+fn generic() {
+    #[derive(Debug, PartialEq, Eq, Ordinalize)]
+    #[ordinalize(bincode)]
+    enum Slot<const N: usize> {
+        A = 1,
+        B = 2,
+    }
+
+    let bytes = bincode::encode_to_vec(Slot::<4>::B, config::standard()).unwrap();
+
+    assert_eq!(vec![2], bytes);
+    assert_eq!(
+        (Slot::<4>::B, 1),
+        bincode::borrow_decode_from_slice(&bytes, config::standard()).unwrap()
+    );
+}
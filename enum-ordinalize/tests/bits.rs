@@ -0,0 +1,192 @@
+#![cfg(all(feature = "derive", feature = "traits"))]
+
+use enum_ordinalize::Ordinalize;
+
+#[derive(Debug, PartialEq, Eq, Ordinalize)]
+#[repr(u8)]
+enum Mode {
+    Input  = 0b00,
+    Output = 0b01,
+    Analog = 0b11,
+}
+
+#[derive(Debug, PartialEq, Eq, Ordinalize)]
+#[repr(i8)]
+enum Offset {
+    Back    = -4,
+    Stay    = 0,
+    Forward = 3,
+}
+
+#[derive(Debug, PartialEq, Eq, Ordinalize)]
+#[ordinalize(bits = "index")]
+#[repr(u16)]
+enum Kind {
+    Header  = 0x0102,
+    Body    = 0x0304,
+    Trailer = 0x0506,
+}
+
+#[derive(Debug, PartialEq, Eq, Ordinalize)]
+enum Single {
+    Only,
+}
+
+enum_ordinalize::ordinalize! {
+    #[derive(Debug, PartialEq, Eq)]
+    #[repr(u32)]
+    enum Prescaler {
+        Div1 = 0,
+        Div256 = 0x100,
+    }
+}
+
+#[test]
+fn bits() {
+    assert_eq!(2, Mode::BITS);
+    assert_eq!(3, Offset::BITS);
+    assert_eq!(2, Kind::BITS);
+    assert_eq!(1, Single::BITS);
+    assert_eq!(9, Prescaler::BITS);
+}
+
+#[test]
+fn into_bits() {
+    assert_eq!(0b11, Mode::Analog.into_bits());
+    assert_eq!(0b100, Offset::Back.into_bits());
+    assert_eq!(0b011, Offset::Forward.into_bits());
+    assert_eq!(2, Kind::Trailer.into_bits());
+    assert_eq!(0, Single::Only.into_bits());
+    assert_eq!(0x100, Prescaler::Div256.into_bits());
+}
+
+#[test]
+fn from_bits() {
+    assert_eq!(Some(Mode::Output), Mode::from_bits(0b01));
+    assert_eq!(None, Mode::from_bits(0b10));
+    assert_eq!(None, Mode::from_bits(0b101));
+
+    assert_eq!(Some(Offset::Back), Offset::from_bits(0b100));
+    assert_eq!(Some(Offset::Stay), Offset::from_bits(0b000));
+    assert_eq!(None, Offset::from_bits(0b111));
+    assert_eq!(None, Offset::from_bits(0b1000));
+
+    assert_eq!(Some(Kind::Body), Kind::from_bits(1));
+    assert_eq!(None, Kind::from_bits(3));
+
+    assert_eq!(Some(Single::Only), Single::from_bits(0));
+    assert_eq!(None, Single::from_bits(1));
+
+    assert_eq!(Some(Prescaler::Div256), Prescaler::from_bits(0x100));
+    assert_eq!(None, Prescaler::from_bits(0x200));
+}
+
+#[test]
+fn wide_values() {
+    // the values need more than 64 bits, so the variants are encoded as their indexes
+    #[derive(Debug, PartialEq, Eq, Ordinalize)]
+    #[repr(u128)]
+    enum Wide {
+        Low  = 1,
+        High = 0x1_0000_0000_0000_0000,
+        Top  = u128::MAX,
+    }
+
+    // the same for constants, whose values are only known at compile time
+    #[derive(Debug, PartialEq, Eq, Ordinalize)]
+    #[repr(i128)]
+    enum WideConstant {
+        Low  = 1,
+        High = 1 << 64,
+    }
+
+    // the values fit in 64 bits, so the variants are encoded as their values
+    #[derive(Debug, PartialEq, Eq, Ordinalize)]
+    #[repr(i128)]
+    enum Narrow {
+        Min = i64::MIN as i128,
+        Max = i64::MAX as i128,
+    }
+
+    assert_eq!(2, Wide::BITS);
+    assert_eq!(1, Wide::High.into_bits());
+    assert_eq!(Some(Wide::Top), Wide::from_bits(2));
+    assert_eq!(None, Wide::from_bits(3));
+
+    assert_eq!(1, WideConstant::BITS);
+    assert_eq!(1, WideConstant::High.into_bits());
+    assert_eq!(Some(WideConstant::Low), WideConstant::from_bits(0));
+    assert_eq!(None, WideConstant::from_bits(2));
+
+    assert_eq!(64, Narrow::BITS);
+    assert_eq!(1 << 63, Narrow::Min.into_bits());
+    assert_eq!(Some(Narrow::Max), Narrow::from_bits(i64::MAX as u64));
+}
+
+#[test]
+fn signed_values() {
+    // the type of the values is signed, but none of them is negative, so they are not encoded in two's complement
+    #[derive(Debug, PartialEq, Eq, Ordinalize)]
+    enum Two {
+        A,
+        B,
+    }
+
+    #[derive(Debug, PartialEq, Eq, Ordinalize)]
+    enum Four {
+        A,
+        B,
+        C,
+        D,
+    }
+
+    #[derive(Debug, PartialEq, Eq, Ordinalize)]
+    #[repr(i128)]
+    enum Large {
+        Zero = 0,
+        Max  = u64::MAX as i128,
+    }
+
+    enum_ordinalize::ordinalize! {
+        #[derive(Debug, PartialEq, Eq)]
+        #[repr(i8)]
+        enum Level {
+            Low = 0,
+            High = 3,
+        }
+    }
+
+    // one of the values is negative, so they are encoded in two's complement
+    enum_ordinalize::ordinalize! {
+        #[derive(Debug, PartialEq, Eq)]
+        #[repr(i16)]
+        enum Delta {
+            Down = -1,
+            Up = 1,
+        }
+    }
+
+    assert_eq!(1, Two::BITS);
+    assert_eq!(Some(Two::B), Two::from_bits(1));
+    assert_eq!(None, Two::from_bits(2));
+
+    assert_eq!(2, Four::BITS);
+    assert_eq!(0b11, Four::D.into_bits());
+    assert_eq!(Some(Four::D), Four::from_bits(0b11));
+    assert_eq!(None, Four::from_bits(0b100));
+
+    assert_eq!(64, Large::BITS);
+    assert_eq!(u64::MAX, Large::Max.into_bits());
+    assert_eq!(Some(Large::Max), Large::from_bits(u64::MAX));
+
+    assert_eq!(2, Level::BITS);
+    assert_eq!(0b11, Level::High.into_bits());
+    assert_eq!(Some(Level::High), Level::from_bits(0b11));
+    assert_eq!(None, Level::from_bits(0b10));
+
+    assert_eq!(2, Delta::BITS);
+    assert_eq!(0b11, Delta::Down.into_bits());
+    assert_eq!(Some(Delta::Down), Delta::from_bits(0b11));
+    assert_eq!(Some(Delta::Up), Delta::from_bits(0b01));
+    assert_eq!(None, Delta::from_bits(0b10));
+}
@@ -0,0 +1,70 @@
+#![cfg(all(feature = "derive", feature = "borsh"))]
+
+use borsh::{io::ErrorKind, BorshDeserialize};
+use enum_ordinalize::Ordinalize;
+
+#[derive(Debug, PartialEq, Eq, Ordinalize)]
+#[ordinalize(borsh)]
+#[repr(u16)]
+enum Kind {
+    Header = 0x0102,
+    Body   = 0x0304,
+}
+
+#[derive(Debug, PartialEq, Eq, Ordinalize)]
+#[ordinalize(borsh, encode = "index")]
+#[repr(u16)]
+enum IndexedKind {
+    Header = 0x0102,
+    Body   = 0x0304,
+}
+
+#[test]
+fn value() {
+    assert_eq!(vec![0x04, 0x03], borsh::to_vec(&Kind::Body).unwrap());
+    assert_eq!(Kind::Header, borsh::from_slice::<Kind>(&[0x02, 0x01]).unwrap());
+
+    let error = borsh::from_slice::<Kind>(&[0x01, 0x02]).unwrap_err();
+
+    assert_eq!(ErrorKind::InvalidData, error.kind());
+}
+
+#[test]
+fn index() {
+    assert_eq!(vec![1], borsh::to_vec(&IndexedKind::Body).unwrap());
+    assert_eq!(IndexedKind::Header, borsh::from_slice::<IndexedKind>(&[0]).unwrap());
+
+    let error = IndexedKind::try_from_slice(&[2]).unwrap_err();
+
+    assert_eq!(ErrorKind::InvalidData, error.kind());
+}
+
+#[test]
+fn own_implementation() {
+    // without the `borsh` attribute, the enum can implement the traits of borsh by itself
+    #[derive(Debug, PartialEq, Eq, Ordinalize)]
+    enum MyEnum {
+        A = 7,
+    }
+
+    impl borsh::BorshSerialize for MyEnum {
+        fn serialize<W: borsh::io::Write>(&self, writer: &mut W) -> borsh::io::Result<()> {
+            writer.write_all(&[self.ordinal() as u8 + 1])
+        }
+    }
+
+    assert_eq!(vec![8], borsh::to_vec(&MyEnum::A).unwrap());
+}
+
+#[test]
+fn generic() {
+    #[derive(Debug, PartialEq, Eq, Ordinalize)]
+    #[ordinalize(borsh)]
+    enum Slot<const N: usize> {
+        A = 1,
+        B = 2,
+    }
+
+    assert_eq!(vec![2], borsh::to_vec(&Slot::<4>::B).unwrap());
+    assert_eq!(Slot::<4>::A, borsh::from_slice::<Slot<4>>(&[1]).unwrap());
+}
@@ -0,0 +1,50 @@
+#![cfg(all(feature = "derive", feature = "traits"))]
+
+use enum_ordinalize::{BigEndian, Endianness, LittleEndian, NativeEndian, Ordinalize};
+
+#[derive(Debug, PartialEq, Eq, Ordinalize)]
+#[repr(u16)]
+enum Kind {
+    Header = 0x0102,
+    Body   = 0x0304,
+}
+
+#[derive(Debug, PartialEq, Eq, Ordinalize)]
+#[repr(i8)]
+enum Sign {
+    Negative = -1,
+    Zero     = 0,
+    Positive = 1,
+}
+
+#[test]
+fn to_bytes() {
+    assert_eq!([0x01, 0x02], Kind::Header.to_be_bytes());
+    assert_eq!([0x02, 0x01], Kind::Header.to_le_bytes());
+    assert_eq!(0x0304u16.to_ne_bytes(), Kind::Body.to_ne_bytes());
+
+    assert_eq!([0xFF], Sign::Negative.to_be_bytes());
+    assert_eq!([0x01], Sign::Positive.to_le_bytes());
+}
+
+#[test]
+fn from_bytes() {
+    assert_eq!(Some(Kind::Header), Kind::from_be_bytes([0x01, 0x02]));
+    assert_eq!(Some(Kind::Header), Kind::from_le_bytes([0x02, 0x01]));
+    assert_eq!(Some(Kind::Body), Kind::from_ne_bytes(0x0304u16.to_ne_bytes()));
+    assert_eq!(None, Kind::from_be_bytes([0x02, 0x01]));
+    assert_eq!(None, Kind::from_le_bytes([0x00, 0x00]));
+
+    assert_eq!(Some(Sign::Negative), Sign::from_be_bytes([0xFF]));
+    assert_eq!(None, Sign::from_le_bytes([0x02]));
+}
+
+#[test]
+fn endianness() {
+    assert_eq!([0x01, 0x02], BigEndian::to_bytes(0x0102u16));
+    assert_eq!([0x02, 0x01], LittleEndian::to_bytes(0x0102u16));
+    assert_eq!(0x0102u16.to_ne_bytes(), NativeEndian::to_bytes(0x0102u16));
+
+    assert_eq!(0x0102u16, BigEndian::from_bytes::<u16>([0x01, 0x02]));
+    assert_eq!(0x0102u16, LittleEndian::from_bytes::<u16>([0x02, 0x01]));
+}
@@ -0,0 +1,76 @@
+#![cfg(all(feature = "derive", feature = "clap"))]
+
+use clap::{Parser, ValueEnum};
+use enum_ordinalize::Ordinalize;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Ordinalize)]
+#[ordinalize(clap)]
+enum Format {
+    /// Plain text
+    /// without colors.
+    PlainText,
+    /// JSON lines.
+    ///
+    /// One object per line.
+    JsonLines,
+    #[ordinalize(clap(name = "yml"))]
+    Yaml,
+    #[ordinalize(clap(hide))]
+    HTTPDebug,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Ordinalize)]
+#[ordinalize(clap(rename_all = "SCREAMING_SNAKE_CASE", ordinal))]
+enum Level {
+    Info  = 2,
+    Warn  = 1,
+    Error = 0,
+}
+
+#[derive(Debug, Parser)]
+struct Cli {
+    #[arg(long, value_enum, default_value = "plain-text")]
+    format: Format,
+    #[arg(long, value_enum)]
+    level:  Option<Level>,
+}
+
+#[test]
+fn possible_values() {
+    let names: Vec<String> = Format::value_variants()
+        .iter()
+        .map(|variant| variant.to_possible_value().unwrap().get_name().to_string())
+        .collect();
+
+    assert_eq!(vec!["plain-text", "json-lines", "yml", "http-debug"], names);
+
+    let possible_value = Format::PlainText.to_possible_value().unwrap();
+    assert_eq!("Plain text without colors.", possible_value.get_help().unwrap().to_string());
+
+    let possible_value = Format::JsonLines.to_possible_value().unwrap();
+    assert_eq!("JSON lines.", possible_value.get_help().unwrap().to_string());
+
+    assert!(Format::Yaml.to_possible_value().unwrap().get_help().is_none());
+    assert!(Format::HTTPDebug.to_possible_value().unwrap().is_hide_set());
+
+    assert_eq!(Ok(Level::Warn), Level::from_str("WARN", false));
+    assert_eq!(Ok(Level::Warn), Level::from_str("warn", true));
+    assert_eq!(Ok(Level::Error), Level::from_str("0", false));
+    assert!(Format::from_str("0", false).is_err());
+}
+
+#[test]
+fn parse_arguments() {
+    let cli = Cli::try_parse_from(["app", "--format", "json-lines", "--level", "INFO"]).unwrap();
+
+    assert_eq!(Format::JsonLines, cli.format);
+    assert_eq!(Some(Level::Info), cli.level);
+
+    let cli = Cli::try_parse_from(["app", "--level", "1"]).unwrap();
+
+    assert_eq!(Format::PlainText, cli.format);
+    assert_eq!(Some(Level::Warn), cli.level);
+
+    assert!(Cli::try_parse_from(["app", "--format", "yaml"]).is_err());
+    assert!(Cli::try_parse_from(["app", "--level", "3"]).is_err());
+}
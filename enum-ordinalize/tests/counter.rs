@@ -0,0 +1,137 @@
+#![cfg(all(feature = "derive", feature = "traits", feature = "alloc"))]
+
+use std::{sync::Arc, thread};
+
+use enum_ordinalize::{AtomicEnumCounter, EnumCounter, Ordinalize};
+
+#[derive(Debug, PartialEq, Eq, Ordinalize)]
+#[repr(u16)]
+enum Status {
+    Ok                  = 200,
+    NotFound            = 404,
+    InternalServerError = 500,
+}
+
+#[test]
+fn count() {
+    let mut counter = EnumCounter::new();
+
+    assert_eq!(None, counter.most_common());
+
+    counter.increment(&Status::NotFound);
+    counter.add(&Status::Ok, 10);
+    counter.extend([&Status::NotFound, &Status::InternalServerError]);
+
+    assert_eq!(10, counter.get(&Status::Ok));
+    assert_eq!(2, counter.get(&Status::NotFound));
+    assert_eq!(13, counter.total());
+    assert_eq!(Some((Status::Ok, 10)), counter.most_common());
+    assert_eq!(
+        vec![(Status::InternalServerError, 1), (Status::NotFound, 2), (Status::Ok, 10)],
+        counter.iter().rev().collect::<Vec<_>>()
+    );
+    assert_eq!("{Ok: 10, NotFound: 2, InternalServerError: 1}", format!("{counter:?}"));
+
+    counter.clear();
+
+    assert_eq!(EnumCounter::default(), counter);
+}
+
+#[test]
+fn most_common_tie() {
+    let counter: EnumCounter<Status> =
+        [Status::InternalServerError, Status::NotFound].into_iter().collect();
+
+    assert_eq!(Some((Status::NotFound, 1)), counter.most_common());
+}
+
+#[test]
+fn merge() {
+    let mut a: EnumCounter<Status> = [Status::Ok, Status::Ok].into_iter().collect();
+    let b: EnumCounter<Status> = [Status::Ok, Status::NotFound].into_iter().collect();
+
+    a.merge(&b);
+
+    assert_eq!(3, a.get(&Status::Ok));
+    assert_eq!(1, a.get(&Status::NotFound));
+    assert_eq!(0, a.get(&Status::InternalServerError));
+}
+
+#[test]
+fn wrapping() {
+    let mut a = EnumCounter::new();
+
+    a.add(&Status::Ok, u64::MAX);
+    a.increment(&Status::NotFound);
+
+    assert_eq!(0, a.total());
+
+    a.merge(&a.clone());
+    a.increment(&Status::Ok);
+
+    assert_eq!(u64::MAX, a.get(&Status::Ok));
+    assert_eq!(2, a.get(&Status::NotFound));
+}
+
+#[test]
+fn display() {
+    let mut counter = EnumCounter::new();
+
+    counter.add(&Status::Ok, 1024);
+    counter.add(&Status::NotFound, 7);
+
+    assert_eq!(
+        "Ok                  1024\nNotFound               7\nInternalServerError    0",
+        counter.to_string()
+    );
+}
+
+#[test]
+fn display_non_ascii() {
+    // the names are padded by their characters rather than their bytes
+    #[derive(Debug, PartialEq, Eq, Ordinalize)]
+    enum Drink {
+        Café,
+        Tea,
+    }
+
+    let mut counter = EnumCounter::new();
+
+    counter.add(&Drink::Café, 12);
+    counter.add(&Drink::Tea, 3);
+
+    assert_eq!("Café 12\nTea   3", counter.to_string());
+}
+
+#[test]
+fn atomic() {
+    let counter = Arc::new(AtomicEnumCounter::new());
+
+    let handles: Vec<_> = (0..4)
+        .map(|_| {
+            let counter = Arc::clone(&counter);
+
+            thread::spawn(move || {
+                for _ in 0..100 {
+                    counter.increment(&Status::Ok);
+                }
+
+                counter.increment(&Status::InternalServerError);
+            })
+        })
+        .collect();
+
+    for handle in handles {
+        handle.join().unwrap();
+    }
+
+    assert_eq!(400, counter.get(&Status::Ok));
+    assert_eq!(4, counter.get(&Status::InternalServerError));
+
+    counter.merge(&[Status::NotFound].into_iter().collect());
+
+    let snapshot = counter.take();
+
+    assert_eq!(405, snapshot.total());
+    assert_eq!(0, counter.snapshot().total());
+}
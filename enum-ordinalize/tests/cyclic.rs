@@ -0,0 +1,42 @@
+#![cfg(all(feature = "derive", feature = "traits"))]
+
+use enum_ordinalize::{Cyclic, Ordinalize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Ordinalize)]
+enum Direction {
+    North = 0,
+    East  = 90,
+    South = 180,
+    West  = -90,
+}
+
+#[test]
+fn add_sub() {
+    let north = Cyclic::new(Direction::North);
+
+    assert_eq!(Direction::East, *(north + 1));
+    assert_eq!(Direction::West, *(north + 3));
+    assert_eq!(Direction::North, *(north + 4));
+    assert_eq!(Direction::West, *(north + -1));
+    assert_eq!(Direction::West, *(north - 1));
+    assert_eq!(Direction::South, *(north - 6));
+
+    assert_eq!(Direction::North, *(north + isize::MAX + 1));
+    assert_eq!(Direction::North, *(north - isize::MIN));
+
+    let mut direction = north;
+
+    direction += 2;
+    assert_eq!(Direction::South, direction.into_inner());
+
+    direction -= 3;
+    assert_eq!(Direction::West, direction.into_inner());
+}
+
+#[test]
+fn distance() {
+    assert_eq!(0, Cyclic::distance(&Direction::East, &Direction::East));
+    assert_eq!(2, Cyclic::distance(&Direction::North, &Direction::South));
+    assert_eq!(1, Cyclic::distance(&Direction::West, &Direction::North));
+    assert_eq!(3, Cyclic::distance(&Direction::East, &Direction::North));
+}
@@ -0,0 +1,73 @@
+#![cfg(all(feature = "derive", feature = "defmt"))]
+
+use defmt::{export::fetch_bytes, Format};
+use enum_ordinalize::Ordinalize;
+
+#[derive(Ordinalize)]
+#[ordinalize(defmt = "name")]
+enum Level {
+    Info,
+    Warn,
+}
+
+#[derive(Ordinalize)]
+#[ordinalize(defmt = "ordinal")]
+#[repr(u16)]
+enum Kind {
+    Header = 0x0102,
+    Body   = 0x0304,
+}
+
+fn assert_format<T: Format>() {}
+
+fn contains(bytes: &[u8], part: &[u8]) -> bool {
+    bytes.windows(part.len()).any(|window| window == part)
+}
+
+#[test]
+fn format() {
+    assert_format::<Level>();
+    assert_format::<Kind>();
+
+    // only the index of the interned name is written
+    defmt::println!("{}", Level::Warn);
+
+    let bytes = fetch_bytes();
+    assert!(!bytes.is_empty());
+    assert!(!contains(&bytes, b"Warn"));
+
+    defmt::println!("{}", Kind::Body);
+
+    let bytes = fetch_bytes();
+    assert!(contains(&bytes, &0x0304u16.to_le_bytes()));
+}
+
+#[test]
+fn own_implementation() {
+    // without the `defmt` attribute, the enum can implement `Format` by itself
+    #[derive(Ordinalize)]
+    enum Flag {
+        Off,
+        On,
+    }
+
+    impl Format for Flag {
+        fn format(&self, fmt: defmt::Formatter) {
+            defmt::write!(fmt, "{}", self.ordinal() as u8 == 1);
+        }
+    }
+
+    assert_format::<Flag>();
+}
+
+#[test]
+fn generic() {
+    #[derive(Ordinalize)]
+    #[ordinalize(defmt = "ordinal")]
+    enum Slot<const N: usize> {
+        A,
+        B,
+    }
+
+    assert_format::<Slot<4>>();
+}
@@ -108,3 +108,20 @@ fn create_ordinalized_enum_5_2() {
     assert_eq!(MyEnum::Twelve, unsafe { MyEnum::from_ordinal_unsafe(12i8) });
     assert_eq!(MyEnum::HundredTwo, unsafe { MyEnum::from_ordinal_unsafe(102i8) });
 }
+
+#[test]
+fn ordinalize_attribute_after_repr() {
+    #[derive(Debug, PartialEq, Eq, Ordinalize)]
+    #[repr(u8)]
+    #[ordinalize(default = B, bits = "index")]
+    enum MyEnum {
+        A = 10,
+        B = 20,
+        C = 30,
+    }
+
+    assert_eq!(MyEnum::B, MyEnum::default());
+
+    assert_eq!(2, MyEnum::BITS);
+    assert_eq!(2, MyEnum::C.into_bits());
+}
@@ -0,0 +1,96 @@
+#![cfg(feature = "derive")]
+
+use core::hash::{BuildHasher, Hash, Hasher};
+use std::collections::hash_map::RandomState;
+
+use enum_ordinalize::Ordinalize;
+
+fn hash_of<T: Hash>(state: &RandomState, value: &T) -> u64 {
+    let mut hasher = state.build_hasher();
+
+    value.hash(&mut hasher);
+
+    hasher.finish()
+}
+
+#[test]
+fn ord_value() {
+    #[derive(Debug, PartialEq, Eq, Ordinalize)]
+    #[ordinalize(ord = "value")]
+    enum Priority {
+        High = 3,
+        Low  = 1,
+        Mid  = 2,
+    }
+
+    assert!(Priority::Low < Priority::Mid);
+    assert!(Priority::Mid < Priority::High);
+
+    let mut priorities = vec![Priority::High, Priority::Low, Priority::Mid];
+
+    priorities.sort();
+
+    assert_eq!(vec![Priority::Low, Priority::Mid, Priority::High], priorities);
+}
+
+#[test]
+fn ord_declaration() {
+    #[derive(Debug, PartialEq, Eq, Ordinalize)]
+    #[ordinalize(ord = "declaration")]
+    enum Priority {
+        High = 3,
+        Low  = 1,
+        Mid  = 2,
+    }
+
+    assert!(Priority::High < Priority::Low);
+    assert!(Priority::Low < Priority::Mid);
+    assert_eq!(Some(core::cmp::Ordering::Equal), Priority::Mid.partial_cmp(&Priority::Mid));
+
+    let mut priorities = vec![Priority::Mid, Priority::Low, Priority::High];
+
+    priorities.sort();
+
+    assert_eq!(vec![Priority::High, Priority::Low, Priority::Mid], priorities);
+}
+
+#[test]
+fn hash() {
+    #[derive(Debug, PartialEq, Eq, Ordinalize)]
+    #[ordinalize(hash)]
+    #[repr(u16)]
+    enum Code {
+        Ok       = 200,
+        NotFound = 404,
+    }
+
+    let state = RandomState::new();
+
+    assert_eq!(hash_of(&state, &200u16), hash_of(&state, &Code::Ok));
+    assert_eq!(hash_of(&state, &404u16), hash_of(&state, &Code::NotFound));
+}
+
+#[test]
+fn default_first() {
+    #[derive(Debug, PartialEq, Eq, Ordinalize)]
+    #[ordinalize(default)]
+    enum Level {
+        Info = 2,
+        Warn = 1,
+    }
+
+    assert_eq!(Level::Info, Level::default());
+}
+
+#[test]
+fn default_named() {
+    #[derive(Debug, PartialEq, Eq, Ordinalize)]
+    #[ordinalize(default = Warn, ord = "value", hash)]
+    enum Level {
+        Info = 2,
+        Warn = 1,
+    }
+
+    assert_eq!(Level::Warn, Level::default());
+    assert!(Level::Warn < Level::Info);
+}
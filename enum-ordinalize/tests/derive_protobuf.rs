@@ -0,0 +1,70 @@
+#![cfg(feature = "derive")]
+
+use enum_ordinalize::{Ordinalize, UnknownEnumValue};
+
+#[derive(Debug, PartialEq, Eq, Ordinalize)]
+#[ordinalize(protobuf = "proto3")]
+#[repr(i32)]
+enum Status {
+    Unspecified     = 0,
+    Ok              = 1,
+    NotFound        = 5,
+    HTTPServerError = 500,
+}
+
+#[test]
+fn from_i32() {
+    assert_eq!(Some(Status::NotFound), Status::from_i32(5));
+    assert_eq!(None, Status::from_i32(2));
+
+    assert!(Status::is_valid(500));
+    assert!(!Status::is_valid(-1));
+
+    assert_eq!(Ok(Status::Ok), Status::try_from(1));
+    assert_eq!(Err(UnknownEnumValue(3)), Status::try_from(3));
+
+    assert_eq!(500, i32::from(Status::HTTPServerError));
+}
+
+#[test]
+fn str_name() {
+    assert_eq!("UNSPECIFIED", Status::Unspecified.as_str_name());
+    assert_eq!("NOT_FOUND", Status::NotFound.as_str_name());
+    assert_eq!("HTTP_SERVER_ERROR", Status::HTTPServerError.as_str_name());
+
+    assert_eq!(Some(Status::NotFound), Status::from_str_name("NOT_FOUND"));
+    assert_eq!(None, Status::from_str_name("NotFound"));
+}
+
+#[test]
+fn other_variant_type() {
+    #[derive(Debug, PartialEq, Eq, Ordinalize)]
+    #[ordinalize(protobuf)]
+    #[repr(u8)]
+    enum Level {
+        Info2 = 2,
+        Warn  = 1 + 2,
+    }
+
+    assert_eq!(Some(Level::Warn), Level::from_i32(3));
+    assert_eq!("INFO2", Level::Info2.as_str_name());
+    assert_eq!(Some(Level::Info2), Level::from_str_name("INFO2"));
+    assert_eq!(Ok(Level::Info2), Level::try_from(2));
+
+    assert_eq!("unknown enumeration value 7", UnknownEnumValue(7).to_string());
+}
+
+#[test]
+fn u128_variant_type() {
+    #[derive(Debug, PartialEq, Eq, Ordinalize)]
+    #[ordinalize(protobuf)]
+    #[repr(u128)]
+    enum Level {
+        Info = 0,
+        Max  = i32::MAX as u128,
+    }
+
+    assert_eq!(Some(Level::Max), Level::from_i32(i32::MAX));
+    assert_eq!(i32::MAX, i32::from(Level::Max));
+    assert_eq!(None, Level::from_i32(-1));
+}
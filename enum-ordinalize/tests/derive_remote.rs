@@ -0,0 +1,46 @@
+#![cfg(all(feature = "derive", feature = "traits"))]
+
+use enum_ordinalize::Ordinalize;
+
+mod other_crate {
+    #[derive(Debug, PartialEq, Eq)]
+    pub enum Mode {
+        Read    = 1,
+        Write   = 2,
+        Execute = 4,
+    }
+}
+
+#[test]
+fn remote() {
+    #[derive(Debug, PartialEq, Eq, Ordinalize)]
+    #[ordinalize(remote = "other_crate::Mode")]
+    enum Mode {
+        Read    = 1,
+        Write   = 2,
+        Execute = 4,
+    }
+
+    assert_eq!(other_crate::Mode::Write, other_crate::Mode::from(Mode::Write));
+    assert_eq!(Mode::Execute, Mode::from(other_crate::Mode::Execute));
+
+    assert_eq!(4i8, Mode::from(other_crate::Mode::Execute).ordinal());
+    assert_eq!(Some(other_crate::Mode::Read), Mode::from_ordinal(1i8).map(other_crate::Mode::from));
+    assert_eq!(None, Mode::from_ordinal(3i8).map(other_crate::Mode::from));
+}
+
+#[test]
+fn remote_impl_trait_false() {
+    #[derive(Debug, PartialEq, Eq, Ordinalize)]
+    #[ordinalize(impl_trait = false)]
+    #[ordinalize(from_ordinal(const fn from_ordinal))]
+    #[ordinalize(remote = "other_crate::Mode")]
+    #[repr(u8)]
+    enum Mode {
+        Read    = 1,
+        Write,
+        Execute = 4,
+    }
+
+    assert_eq!(Some(other_crate::Mode::Write), Mode::from_ordinal(2).map(other_crate::Mode::from));
+}
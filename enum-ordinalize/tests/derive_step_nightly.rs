@@ -0,0 +1,25 @@
+#![cfg(all(feature = "derive", feature = "traits", feature = "nightly-test"))]
+#![cfg_attr(feature = "nightly-test", feature(step_trait))]
+
+use enum_ordinalize::Ordinalize;
+
+#[test]
+fn step() {
+    #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ordinalize)]
+    #[ordinalize(impl_step = true)]
+    enum Level {
+        Trace,
+        Debug,
+        Info = 5,
+        Warn = 10,
+        Error,
+    }
+
+    assert_eq!(
+        vec![Level::Debug, Level::Info, Level::Warn],
+        (Level::Debug..=Level::Warn).collect::<Vec<_>>()
+    );
+    assert_eq!(Some(Level::Warn), (Level::Warn..).next());
+    assert_eq!(0, (Level::Error..Level::Trace).count());
+    assert_eq!(Some(Level::Error), (Level::Trace..=Level::Error).last());
+}
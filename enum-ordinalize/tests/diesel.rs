@@ -0,0 +1,139 @@
+#![cfg(all(feature = "derive", feature = "diesel"))]
+
+use std::error::Error;
+
+use diesel::{prelude::*, result::Error as DieselError, sql_query, sqlite::SqliteConnection};
+use enum_ordinalize::{Ordinalize, UnknownOrdinalError, UnknownVariantNameError};
+
+#[derive(Debug, PartialEq, Eq, Ordinalize)]
+#[ordinalize(sql = "value")]
+#[repr(u16)]
+enum Kind {
+    Header = 0x0102,
+    Body   = 0x0304,
+}
+
+#[derive(Debug, PartialEq, Eq, Ordinalize)]
+#[ordinalize(sql = "value")]
+enum Sign {
+    Negative = -1,
+    Positive = 1,
+}
+
+#[derive(Debug, PartialEq, Eq, Ordinalize)]
+#[ordinalize(sql = "name")]
+enum Level {
+    Info = 2,
+    Warn = 1,
+}
+
+diesel::table! {
+    records (id) {
+        id -> Integer,
+        kind -> Integer,
+        sign -> SmallInt,
+        level -> Nullable<Text>,
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Queryable, Insertable)]
+#[diesel(table_name = records)]
+struct Record {
+    id:    i32,
+    kind:  Kind,
+    sign:  Sign,
+    level: Option<Level>,
+}
+
+fn deserialization_error(error: &DieselError) -> &(dyn Error + 'static) {
+    let mut error: &(dyn Error + 'static) = match error {
+        DieselError::DeserializationError(error) => error.as_ref(),
+        error => panic!("{error:?}"),
+    };
+
+    // the error of a field may be wrapped
+    while let Some(source) = error.source() {
+        error = source;
+    }
+
+    error
+}
+
+fn connection() -> SqliteConnection {
+    let mut connection = SqliteConnection::establish(":memory:").unwrap();
+
+    sql_query(
+        "CREATE TABLE records (id INTEGER PRIMARY KEY, kind INTEGER NOT NULL, sign SMALLINT NOT \
+         NULL, level TEXT)",
+    )
+    .execute(&mut connection)
+    .unwrap();
+
+    connection
+}
+
+#[test]
+fn round_trip() {
+    let mut connection = connection();
+
+    let record =
+        Record {
+            id: 1, kind: Kind::Body, sign: Sign::Negative, level: Some(Level::Warn)
+        };
+
+    diesel::insert_into(records::table).values(&record).execute(&mut connection).unwrap();
+
+    diesel::insert_into(records::table)
+        .values((
+            records::id.eq(2),
+            records::kind.eq(Kind::Header),
+            records::sign.eq(Sign::Positive),
+        ))
+        .execute(&mut connection)
+        .unwrap();
+
+    let raw: (i32, i16, Option<String>) = records::table
+        .select((records::kind, records::sign, records::level))
+        .filter(records::id.eq(1))
+        .first(&mut connection)
+        .unwrap();
+
+    assert_eq!((0x0304, -1, Some(String::from("Warn"))), raw);
+
+    let loaded: Vec<Record> = records::table.order(records::id).load(&mut connection).unwrap();
+
+    assert_eq!(2, loaded.len());
+    assert_eq!(record, loaded[0]);
+    assert_eq!(None, loaded[1].level);
+
+    let kinds: Vec<Kind> = records::table
+        .select(records::kind)
+        .filter(records::sign.eq(Sign::Positive))
+        .load(&mut connection)
+        .unwrap();
+
+    assert_eq!(vec![Kind::Header], kinds);
+}
+
+#[test]
+fn unknown_value() {
+    let mut connection = connection();
+
+    sql_query("INSERT INTO records (id, kind, sign, level) VALUES (1, 5, 1, 'Error')")
+        .execute(&mut connection)
+        .unwrap();
+
+    let error = records::table.select(records::kind).first::<Kind>(&mut connection).unwrap_err();
+
+    let error = deserialization_error(&error).downcast_ref::<UnknownOrdinalError<Kind>>().unwrap();
+
+    assert_eq!(5, error.value());
+
+    let error =
+        records::table.select(records::level).first::<Option<Level>>(&mut connection).unwrap_err();
+
+    let error =
+        deserialization_error(&error).downcast_ref::<UnknownVariantNameError<Level>>().unwrap();
+
+    assert_eq!("Error", error.name());
+}
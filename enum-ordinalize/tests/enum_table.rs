@@ -0,0 +1,69 @@
+#![cfg(all(feature = "derive", feature = "traits"))]
+
+use enum_ordinalize::{enum_table, Ordinalize};
+
+mod color {
+    use enum_ordinalize::Ordinalize;
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Ordinalize)]
+    #[repr(i8)]
+    pub enum Color {
+        Red   = 10,
+        Green = -1,
+        Blue  = 3,
+    }
+}
+
+use color::Color;
+
+enum_table! {
+    /// The RGB value of each color.
+    #[derive(Debug)]
+    pub struct Rgb: color::Color => u32 {
+        Red => 0xFF0000,
+        Green => 0x00FF00,
+        Blue => 0x0000FF
+    }
+}
+
+enum_table! {
+    struct Weights: Color => (u8, &'static str) {
+        Red => (1, "warm"),
+        Green => (2, "natural"),
+        Blue => (3, "cool"),
+    }
+}
+
+const GREEN: u32 = Rgb.get(&Color::Green);
+
+#[test]
+fn get() {
+    assert_eq!(0x00FF00, GREEN);
+    assert_eq!(0x0000FF, Rgb.get(&Color::Blue));
+    assert_eq!((3, "cool"), Weights.get(&Color::Blue));
+}
+
+#[test]
+fn expression() {
+    #[derive(Debug, PartialEq, Eq, Ordinalize)]
+    enum E {
+        A,
+        B,
+    }
+
+    const B: &str = enum_table!(E => &'static str { A => "a", B => "b" }).get(&E::B);
+
+    let table = enum_table!(E => &'static str { A => "a", B => "b" });
+
+    assert_eq!("b", B);
+    assert_eq!("a", table.get(&E::A));
+}
+
+#[test]
+fn table() {
+    assert_eq!([0xFF0000, 0x00FF00, 0x0000FF], Rgb::TABLE);
+
+    for color in Color::VARIANTS {
+        assert_eq!(Rgb::TABLE[color.variant_index()], Rgb.get(color));
+    }
+}
@@ -0,0 +1,56 @@
+#![cfg(all(feature = "derive", feature = "std"))]
+
+use std::{error::Error, io};
+
+use enum_ordinalize::{BigEndian, LittleEndian, Ordinalize, UnknownOrdinalError};
+
+#[derive(Debug, PartialEq, Eq, Ordinalize)]
+#[repr(u16)]
+enum Kind {
+    Header = 0x0102,
+    Body   = 0x0304,
+}
+
+#[test]
+fn read_from() {
+    let mut data: &[u8] = &[0x01, 0x02, 0x04, 0x03];
+
+    assert_eq!(Kind::Header, Kind::read_from::<BigEndian, _>(&mut data).unwrap());
+    assert_eq!(Kind::Body, Kind::read_from::<LittleEndian, _>(&mut data).unwrap());
+    assert!(data.is_empty());
+}
+
+#[test]
+fn read_from_unknown() {
+    let mut data: &[u8] = &[0x01, 0x03];
+
+    let error = Kind::read_from::<BigEndian, _>(&mut data).unwrap_err();
+
+    assert_eq!(io::ErrorKind::InvalidData, error.kind());
+
+    let error = error.into_inner().unwrap();
+    let error = error.downcast_ref::<UnknownOrdinalError<Kind>>().unwrap();
+
+    assert_eq!(0x0103, error.value());
+    assert!(error.to_string().starts_with("`259` is not the value of any variant of `"));
+    assert!(error.source().is_none());
+}
+
+#[test]
+fn read_from_eof() {
+    let mut data: &[u8] = &[0x01];
+
+    let error = Kind::read_from::<BigEndian, _>(&mut data).unwrap_err();
+
+    assert_eq!(io::ErrorKind::UnexpectedEof, error.kind());
+}
+
+#[test]
+fn write_to() {
+    let mut data = Vec::new();
+
+    Kind::Header.write_to::<BigEndian, _>(&mut data).unwrap();
+    Kind::Body.write_to::<LittleEndian, _>(&mut data).unwrap();
+
+    assert_eq!(vec![0x01, 0x02, 0x04, 0x03], data);
+}
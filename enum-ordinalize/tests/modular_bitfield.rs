@@ -0,0 +1,91 @@
+#![cfg(all(feature = "derive", feature = "modular-bitfield"))]
+
+use enum_ordinalize::Ordinalize;
+use modular_bitfield::{bitfield, specifiers::B3};
+
+#[derive(Debug, PartialEq, Eq, Ordinalize)]
+#[ordinalize(modular_bitfield)]
+#[repr(u8)]
+enum Mode {
+    Input  = 0b00,
+    Output = 0b01,
+    Analog = 0b11,
+}
+
+#[derive(Debug, PartialEq, Eq, Ordinalize)]
+#[ordinalize(modular_bitfield)]
+#[repr(i8)]
+enum Offset {
+    Back    = -4,
+    Stay    = 0,
+    Forward = 3,
+}
+
+#[bitfield]
+struct Register {
+    mode:     Mode,
+    offset:   Offset,
+    #[skip]
+    reserved: B3,
+}
+
+#[test]
+fn pack() {
+    let register = Register::new().with_mode(Mode::Analog).with_offset(Offset::Back);
+
+    assert_eq!([0b1_0011], register.into_bytes());
+}
+
+#[test]
+fn unpack() {
+    let register = Register::from_bytes([0b0_1101]);
+
+    assert_eq!(Mode::Output, register.mode());
+    assert_eq!(Offset::Forward, register.offset());
+
+    let register = Register::from_bytes([0b0_0010]);
+
+    assert!(register.mode_or_err().is_err());
+}
+
+#[test]
+fn wide_field() {
+    #[derive(Debug, PartialEq, Eq, Ordinalize)]
+    #[ordinalize(modular_bitfield)]
+    #[repr(u16)]
+    enum Rate {
+        Slow = 0x001,
+        Fast = 0x1FF,
+    }
+
+    #[bitfield]
+    struct Control {
+        rate:     Rate,
+        #[skip]
+        reserved: modular_bitfield::specifiers::B7,
+    }
+
+    assert_eq!(9, <Rate as modular_bitfield::Specifier>::BITS);
+    assert_eq!([0xFF, 0x01], Control::new().with_rate(Rate::Fast).into_bytes());
+    assert_eq!(Rate::Slow, Control::from_bytes([0x01, 0x00]).rate());
+}
+
+#[test]
+fn own_implementation() {
+    // without the `modular_bitfield` attribute, the enum can derive `BitfieldSpecifier` by itself
+    #[derive(Debug, PartialEq, Eq, Ordinalize, modular_bitfield::BitfieldSpecifier)]
+    enum Flag {
+        Off,
+        On,
+    }
+
+    #[bitfield]
+    struct Flags {
+        flag:     Flag,
+        #[skip]
+        reserved: modular_bitfield::specifiers::B7,
+    }
+
+    assert_eq!([0b1], Flags::new().with_flag(Flag::On).into_bytes());
+    assert_eq!(Flag::Off, Flags::from_bytes([0b0]).flag());
+}
@@ -0,0 +1,97 @@
+#![cfg(all(feature = "derive", feature = "nom"))]
+
+use enum_ordinalize::{nom::*, BigEndian, LittleEndian, Ordinalize, UnknownOrdinalError};
+use nom::{
+    error::{Error, ErrorKind, FromExternalError, ParseError},
+    multi::many1,
+    IResult, Parser,
+};
+
+#[derive(Debug, PartialEq, Eq, Ordinalize)]
+#[repr(u16)]
+enum Kind {
+    Header          = 0x0102,
+    HeaderExtension = 0x0103,
+    Body            = 0x0304,
+}
+
+#[derive(Debug, PartialEq)]
+struct UnknownKind(u16);
+
+impl<I> ParseError<I> for UnknownKind {
+    fn from_error_kind(_input: I, _kind: ErrorKind) -> Self {
+        UnknownKind(0)
+    }
+
+    fn append(_input: I, _kind: ErrorKind, other: Self) -> Self {
+        other
+    }
+}
+
+impl<I> FromExternalError<I, UnknownOrdinalError<Kind>> for UnknownKind {
+    fn from_external_error(_input: I, _kind: ErrorKind, e: UnknownOrdinalError<Kind>) -> Self {
+        UnknownKind(e.value())
+    }
+}
+
+#[test]
+fn ordinal_big_endian() {
+    let input: &[u8] = &[0x01, 0x03, 0x03, 0x04, 0xFF];
+
+    let result: IResult<&[u8], Vec<Kind>> = many1(ordinal::<Kind, BigEndian, _, _>()).parse(input);
+
+    assert_eq!(Ok((&[0xFF][..], vec![Kind::HeaderExtension, Kind::Body])), result);
+}
+
+#[test]
+fn ordinal_little_endian() {
+    let input: &[u8] = &[0x02, 0x01];
+
+    let result: IResult<&[u8], Kind> = ordinal::<Kind, LittleEndian, _, _>().parse(input);
+
+    assert_eq!(Ok((&[][..], Kind::Header)), result);
+}
+
+#[test]
+fn ordinal_unknown() {
+    let input: &[u8] = &[0x01, 0x04, 0x03, 0x04];
+
+    let result: IResult<&[u8], Kind> = ordinal::<Kind, BigEndian, _, _>().parse(input);
+
+    assert_eq!(Err(nom::Err::Error(Error::new(input, ErrorKind::MapRes))), result);
+
+    let result: IResult<&[u8], Kind, UnknownKind> = ordinal::<Kind, BigEndian, _, _>().parse(input);
+
+    assert_eq!(Err(nom::Err::Error(UnknownKind(0x0104))), result);
+}
+
+#[test]
+fn ordinal_incomplete() {
+    let input: &[u8] = &[0x01];
+
+    let result: IResult<&[u8], Kind> = ordinal::<Kind, BigEndian, _, _>().parse(input);
+
+    assert_eq!(Err(nom::Err::Error(Error::new(input, ErrorKind::Eof))), result);
+}
+
+#[test]
+fn name_longest() {
+    let result: IResult<&str, Kind> = name::<Kind, _, _>().parse("HeaderExtension=1");
+
+    assert_eq!(Ok(("=1", Kind::HeaderExtension)), result);
+
+    let result: IResult<&str, Kind> = name::<Kind, _, _>().parse("HeaderExt");
+
+    assert_eq!(Ok(("Ext", Kind::Header)), result);
+
+    let result: IResult<&[u8], Kind> = name::<Kind, _, _>().parse(&b"Body"[..]);
+
+    assert_eq!(Ok((&b""[..], Kind::Body)), result);
+}
+
+#[test]
+fn name_unknown() {
+    let result: IResult<&str, Kind> = name::<Kind, _, _>().parse("Footer");
+
+    assert_eq!(Err(nom::Err::Error(Error::new("Footer", ErrorKind::Tag))), result);
+}
@@ -0,0 +1,39 @@
+#![cfg(all(feature = "derive", feature = "traits"))]
+
+use enum_ordinalize::Ordinalize;
+
+#[test]
+fn from_ordinal_any() {
+    #[derive(Debug, PartialEq, Eq, Ordinalize)]
+    enum MyEnum {
+        Zero,
+        One,
+        NegativeOne = -1,
+    }
+
+    assert_eq!(Some(MyEnum::Zero), MyEnum::from_ordinal_any(0u32));
+    assert_eq!(Some(MyEnum::One), MyEnum::from_ordinal_any(1u64));
+    assert_eq!(Some(MyEnum::NegativeOne), MyEnum::from_ordinal_any(-1i64));
+
+    assert_eq!(None, MyEnum::from_ordinal_any(0x100u32));
+    assert_eq!(None, MyEnum::from_ordinal_any(0xFFu8));
+    assert_eq!(None, MyEnum::from_ordinal_any(u128::MAX));
+}
+
+#[test]
+fn ordinal_as() {
+    #[derive(Debug, PartialEq, Eq, Ordinalize)]
+    #[repr(u16)]
+    enum MyEnum {
+        A,
+        B = 255,
+        C = 256,
+    }
+
+    assert_eq!(Some(0u8), MyEnum::A.ordinal_as());
+    assert_eq!(Some(255u8), MyEnum::B.ordinal_as());
+    assert_eq!(None, MyEnum::C.ordinal_as::<u8>());
+
+    assert_eq!(Some(256i64), MyEnum::C.ordinal_as());
+    assert_eq!(None, MyEnum::C.ordinal_as::<i8>());
+}
@@ -0,0 +1,47 @@
+#![cfg(all(feature = "derive", feature = "traits"))]
+
+use std::collections::HashSet;
+
+use enum_ordinalize::{OrdinalInt, Ordinalize};
+
+fn max_ordinal<E: Ordinalize>() -> E::VariantType {
+    E::VALUES.iter().copied().max().unwrap()
+}
+
+fn distinct_ordinals<E: Ordinalize>() -> usize {
+    E::VALUES.iter().collect::<HashSet<_>>().len()
+}
+
+#[test]
+fn generic_ordinal() {
+    #[derive(Debug, PartialEq, Eq, Ordinalize)]
+    enum MyEnum {
+        A = 2,
+        B = -3,
+        C = 1000,
+    }
+
+    assert_eq!(1000i16, max_ordinal::<MyEnum>());
+    assert_eq!(3, distinct_ordinals::<MyEnum>());
+    assert_eq!(16, <MyEnum as Ordinalize>::VariantType::BITS);
+    assert_eq!(Some(-3), MyEnum::B.ordinal().to_i128());
+}
+
+#[test]
+fn ordinal_int() {
+    assert_eq!(Some(u64::MAX as i128), u64::MAX.to_i128());
+    assert_eq!(None, u128::MAX.to_i128());
+
+    assert_eq!(Some(255u8), u8::try_from_i128(255));
+    assert_eq!(None, u8::try_from_i128(256));
+    assert_eq!(None, u8::try_from_i128(-1));
+
+    assert_eq!(i8::MIN, <i8 as OrdinalInt>::MIN);
+    assert_eq!(usize::BITS, <usize as OrdinalInt>::BITS);
+}
+
+#[test]
+fn from_bits_wider_than_u64() {
+    assert_eq!(None, u128::from_bits(1, 65));
+    assert_eq!(None, i128::from_bits(1, 128));
+}
@@ -0,0 +1,111 @@
+#![cfg(feature = "traits")]
+
+use enum_ordinalize::Ordinalize;
+
+#[test]
+fn ordinalize_macro_1() {
+    enum_ordinalize::ordinalize! {
+        #[derive(Debug, PartialEq, Eq)]
+        #[repr(u8)]
+        enum MyEnum {
+            A = 1,
+            B,
+            C,
+        }
+    }
+
+    assert_eq!(3, MyEnum::VARIANT_COUNT);
+    assert_eq!([MyEnum::A, MyEnum::B, MyEnum::C], MyEnum::VARIANTS);
+    assert_eq!([1u8, 2u8, 3u8], MyEnum::VALUES);
+
+    assert_eq!(1u8, MyEnum::A.ordinal());
+    assert_eq!(3u8, MyEnum::C.ordinal());
+
+    assert_eq!(Some(MyEnum::B), MyEnum::from_ordinal(2u8));
+    assert_eq!(None, MyEnum::from_ordinal(0u8));
+
+    assert_eq!(MyEnum::C, unsafe { MyEnum::from_ordinal_unsafe(3u8) });
+}
+
+#[test]
+fn ordinalize_macro_2() {
+    const BASE: i32 = 100;
+
+    enum_ordinalize::ordinalize! {
+        /// Some documentation.
+        #[derive(Debug, PartialEq, Eq)]
+        #[repr(i32)]
+        #[allow(dead_code)]
+        pub(crate) enum MyEnum {
+            /// The first variant.
+            NegativeTwo = -2,
+            NegativeOne,
+            Base = BASE,
+            BasePlusOne,
+        }
+    }
+
+    assert_eq!(4, MyEnum::VARIANT_COUNT);
+    assert_eq!([-2i32, -1i32, 100i32, 101i32], MyEnum::VALUES);
+
+    assert_eq!(101i32, MyEnum::BasePlusOne.ordinal());
+    assert_eq!(Some(MyEnum::NegativeOne), MyEnum::from_ordinal(-1i32));
+    assert_eq!(Some(MyEnum::Base), MyEnum::from_ordinal(100i32));
+    assert_eq!(None, MyEnum::from_ordinal(0i32));
+}
+
+#[test]
+fn ordinalize_macro_single_variant() {
+    enum_ordinalize::ordinalize! {
+        #[derive(Debug, PartialEq, Eq)]
+        #[repr(u16)]
+        enum MyEnum {
+            A = 500,
+        }
+    }
+
+    assert_eq!([500u16], MyEnum::VALUES);
+    assert_eq!(Some(MyEnum::A), MyEnum::from_ordinal(500u16));
+    assert_eq!(MyEnum::A, unsafe { MyEnum::from_ordinal_unsafe(500u16) });
+}
+
+#[test]
+fn ordinalize_macro_variant_index() {
+    enum_ordinalize::ordinalize! {
+        #[derive(Debug, PartialEq, Eq)]
+        #[repr(i8)]
+        enum MyEnum {
+            Some = -5,
+            None = 10,
+            Index,
+        }
+    }
+
+    assert_eq!(0, MyEnum::Some.variant_index());
+    assert_eq!(2, MyEnum::Index.variant_index());
+
+    assert_eq!(Some(MyEnum::Some), MyEnum::from_variant_index(0));
+    assert_eq!(Some(MyEnum::None), MyEnum::from_variant_index(1));
+    assert_eq!(Some(MyEnum::Index), MyEnum::from_variant_index(2));
+    assert_eq!(None, MyEnum::from_variant_index(3));
+}
+
+#[test]
+fn ordinalize_macro_variant_named_index() {
+    enum_ordinalize::ordinalize! {
+        #[derive(Debug, PartialEq, Eq)]
+        #[repr(u8)]
+        #[allow(non_camel_case_types)]
+        enum MyEnum {
+            A,
+            index,
+            B,
+        }
+    }
+
+    assert_eq!(
+        vec![Some(MyEnum::A), Some(MyEnum::index), Some(MyEnum::B), None],
+        (0..4).map(MyEnum::from_variant_index).collect::<Vec<_>>()
+    );
+    assert_eq!(1, MyEnum::index.variant_index());
+}
@@ -0,0 +1,113 @@
+#![cfg(all(feature = "derive", feature = "traits", feature = "alloc"))]
+
+use enum_ordinalize::{Ordinalize, PackedVec};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Ordinalize)]
+enum Single {
+    Only,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Ordinalize)]
+#[repr(i8)]
+enum Tile {
+    Empty = -3,
+    Wall  = 10,
+    Water = 20,
+    Grass = 30,
+    Lava  = 40,
+}
+
+#[test]
+fn element_bits() {
+    assert_eq!(1, PackedVec::<Single>::ELEMENT_BITS);
+    assert_eq!(3, PackedVec::<Tile>::ELEMENT_BITS);
+}
+
+#[test]
+fn push_pop_get_set() {
+    let mut tiles = PackedVec::new();
+
+    assert!(tiles.is_empty());
+    assert_eq!(None, tiles.pop());
+
+    tiles.push(Tile::Wall);
+    tiles.push(Tile::Lava);
+    tiles.push(Tile::Empty);
+
+    assert_eq!(3, tiles.len());
+    assert_eq!(Some(Tile::Lava), tiles.get(1));
+    assert_eq!(None, tiles.get(3));
+
+    tiles.set(1, Tile::Grass);
+
+    assert_eq!(Some(Tile::Grass), tiles.get(1));
+    assert_eq!(&[0b000_011_001], tiles.as_raw_words());
+
+    assert_eq!(Some(Tile::Empty), tiles.pop());
+    assert_eq!(Some(Tile::Grass), tiles.pop());
+    assert_eq!(&[0b001], tiles.as_raw_words());
+
+    tiles.clear();
+
+    assert!(tiles.is_empty());
+    assert!(tiles.as_raw_words().is_empty());
+}
+
+#[test]
+#[should_panic]
+fn set_out_of_bounds() {
+    let mut tiles = PackedVec::new();
+
+    tiles.push(Tile::Wall);
+    tiles.set(1, Tile::Wall);
+}
+
+#[test]
+fn across_words() {
+    let variants = Tile::VARIANTS;
+
+    let tiles: PackedVec<Tile> = (0..100).map(|i| variants[i % variants.len()]).collect();
+
+    // 100 elements of 3 bits
+    assert_eq!(5, tiles.as_raw_words().len());
+
+    for (i, tile) in tiles.iter().enumerate() {
+        assert_eq!(variants[i % variants.len()], tile);
+    }
+
+    assert_eq!(Some(Tile::Lava), tiles.iter().next_back());
+    assert_eq!(100, tiles.iter().len());
+
+    let mut tiles = tiles;
+
+    for i in 0..100 {
+        tiles.set(i, Tile::Lava);
+    }
+
+    assert!(tiles.iter().all(|tile| tile == Tile::Lava));
+
+    while tiles.len() > 1 {
+        tiles.pop();
+    }
+
+    assert_eq!(&[0b100], tiles.as_raw_words());
+}
+
+#[test]
+fn raw_words() {
+    let tiles: PackedVec<Tile> = [Tile::Water, Tile::Lava, Tile::Grass].into_iter().collect();
+
+    let restored = PackedVec::<Tile>::from_raw_words(tiles.as_raw_words().to_vec(), 3).unwrap();
+
+    assert_eq!(tiles, restored);
+    assert_eq!("[Water, Lava, Grass]", format!("{restored:?}"));
+
+    // wrong number of words
+    assert_eq!(None, PackedVec::<Tile>::from_raw_words(vec![0, 0], 3));
+    // index 5 is not a variant
+    assert_eq!(None, PackedVec::<Tile>::from_raw_words(vec![0b101], 1));
+    // bits after the last element
+    assert_eq!(None, PackedVec::<Tile>::from_raw_words(vec![0b1_000], 1));
+    // the number of bits overflows
+    assert_eq!(None, PackedVec::<Tile>::from_raw_words(vec![], usize::MAX));
+}
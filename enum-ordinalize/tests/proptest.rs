@@ -0,0 +1,45 @@
+#![cfg(all(feature = "derive", feature = "proptest"))]
+
+use enum_ordinalize::{proptest::*, Ordinalize};
+use proptest::prelude::*;
+
+#[derive(Debug, PartialEq, Eq, Ordinalize)]
+#[repr(u8)]
+enum MyEnum {
+    A = 0,
+    B = 2,
+    C = 3,
+    D = 255,
+}
+
+#[derive(Debug, PartialEq, Eq, Ordinalize)]
+#[repr(i128)]
+enum Wide {
+    Min  = i128::MIN,
+    Zero = 0,
+    Max  = i128::MAX,
+}
+
+proptest! {
+    #[test]
+    fn variant(variant in any_variant::<MyEnum>()) {
+        let number = variant.ordinal();
+
+        prop_assert_eq!(Some(variant), MyEnum::from_ordinal(number));
+    }
+
+    #[test]
+    fn valid_ordinal(number in any_valid_ordinal::<MyEnum>()) {
+        prop_assert!(MyEnum::from_ordinal(number).is_some());
+    }
+
+    #[test]
+    fn invalid_ordinal(number in any_invalid_ordinal::<MyEnum>()) {
+        prop_assert!(MyEnum::from_ordinal(number).is_none());
+    }
+
+    #[test]
+    fn invalid_ordinal_wide(number in any_invalid_ordinal::<Wide>()) {
+        prop_assert!(Wide::from_ordinal(number).is_none());
+    }
+}
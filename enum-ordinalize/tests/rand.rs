@@ -0,0 +1,76 @@
+#![cfg(all(feature = "derive", feature = "rand"))]
+
+use enum_ordinalize::Ordinalize;
+use rand::{rngs::SmallRng, Rng, SeedableRng};
+
+#[test]
+fn uniform() {
+    #[derive(Debug, PartialEq, Eq, Ordinalize)]
+    #[ordinalize(rand)]
+    enum MyEnum {
+        A = 5,
+        B = -3,
+        C = 100,
+    }
+
+    let mut rng = SmallRng::seed_from_u64(1);
+    let mut counts = [0usize; 3];
+
+    for _ in 0..3000 {
+        let variant: MyEnum = rng.gen();
+
+        counts[variant.variant_index()] += 1;
+    }
+
+    for count in counts {
+        assert!((800..1200).contains(&count), "{counts:?}");
+    }
+}
+
+#[test]
+fn weighted() {
+    #[derive(Debug, PartialEq, Eq, Ordinalize)]
+    #[ordinalize(rand)]
+    enum MyEnum {
+        #[ordinalize(weight = 3)]
+        A,
+        B,
+        #[ordinalize(weight = 0)]
+        C,
+    }
+
+    let mut rng = SmallRng::seed_from_u64(1);
+    let mut counts = [0usize; 3];
+
+    for _ in 0..4000 {
+        let variant: MyEnum = rng.gen();
+
+        counts[variant.variant_index()] += 1;
+    }
+
+    assert!((2800..3200).contains(&counts[0]), "{counts:?}");
+    assert!((800..1200).contains(&counts[1]), "{counts:?}");
+    assert_eq!(0, counts[2]);
+}
+
+#[test]
+fn own_distribution() {
+    use rand::distributions::{Distribution, Standard};
+
+    // without the `rand` attribute, no implementation is generated to conflict with this one
+    #[derive(Debug, PartialEq, Eq, Ordinalize)]
+    enum MyEnum {
+        A,
+        B,
+    }
+
+    impl Distribution<MyEnum> for Standard {
+        fn sample<R: Rng + ?Sized>(&self, _rng: &mut R) -> MyEnum {
+            MyEnum::B
+        }
+    }
+
+    let mut rng = SmallRng::seed_from_u64(1);
+
+    assert_eq!(MyEnum::B, rng.gen());
+}
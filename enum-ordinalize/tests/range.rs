@@ -0,0 +1,131 @@
+#![cfg(all(feature = "derive", feature = "traits"))]
+
+use enum_ordinalize::Ordinalize;
+
+#[derive(Debug, PartialEq, Eq, Ordinalize)]
+enum Level {
+    Trace = 10,
+    Debug = 5,
+    Info  = 20,
+    Warn  = 0,
+    Error = 30,
+}
+
+#[test]
+fn variant_index() {
+    assert_eq!(0, Level::Trace.variant_index());
+    assert_eq!(3, Level::Warn.variant_index());
+    assert_eq!(4, Level::Error.variant_index());
+
+    assert_eq!(Some(Level::Debug), Level::from_variant_index(1));
+    assert_eq!(Some(Level::Error), Level::from_variant_index(4));
+    assert_eq!(None, Level::from_variant_index(5));
+}
+
+#[test]
+fn range() {
+    assert_eq!(vec![Level::Warn, Level::Error], Level::range(Level::Warn..).collect::<Vec<_>>());
+    assert_eq!(
+        vec![Level::Debug, Level::Info, Level::Warn],
+        Level::range(Level::Debug..=Level::Warn).collect::<Vec<_>>()
+    );
+    assert_eq!(vec![Level::Trace], Level::range(..Level::Debug).collect::<Vec<_>>());
+    assert_eq!(5, Level::range(..).len());
+    assert_eq!(0, Level::range(Level::Warn..Level::Debug).count());
+
+    assert_eq!(
+        vec![Level::Error, Level::Warn, Level::Info],
+        Level::range(Level::Info..).rev().collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn range_manual_impl() {
+    #[derive(Debug, PartialEq, Eq)]
+    #[repr(u8)]
+    enum MyEnum {
+        A = 2,
+        B = 1,
+        C = 0,
+    }
+
+    impl Ordinalize for MyEnum {
+        type VariantType = u8;
+
+        const VALUES: &'static [Self::VariantType] = &[2, 1, 0];
+        const VARIANTS: &'static [Self] = &[MyEnum::A, MyEnum::B, MyEnum::C];
+        const VARIANT_COUNT: usize = 3;
+
+        #[inline]
+        unsafe fn from_ordinal_unsafe(number: Self::VariantType) -> Self {
+            ::core::mem::transmute(number)
+        }
+
+        #[inline]
+        fn from_ordinal(number: Self::VariantType) -> Option<Self> {
+            match number {
+                2 => Some(Self::A),
+                1 => Some(Self::B),
+                0 => Some(Self::C),
+                _ => None,
+            }
+        }
+
+        #[inline]
+        fn ordinal(&self) -> Self::VariantType {
+            match self {
+                Self::A => 2,
+                Self::B => 1,
+                Self::C => 0,
+            }
+        }
+    }
+
+    assert_eq!(2, MyEnum::C.variant_index());
+    assert_eq!(Some(MyEnum::B), MyEnum::from_variant_index(1));
+    assert_eq!(vec![MyEnum::B, MyEnum::C], MyEnum::range(MyEnum::B..).collect::<Vec<_>>());
+}
+
+#[test]
+fn from_variant_index_wrong_values() {
+    #[derive(Debug, PartialEq, Eq)]
+    enum MyEnum {
+        A,
+        B,
+    }
+
+    impl Ordinalize for MyEnum {
+        type VariantType = u8;
+
+        // `7` does not correspond to any variant
+        const VALUES: &'static [Self::VariantType] = &[0, 7];
+        const VARIANTS: &'static [Self] = &[MyEnum::A, MyEnum::B];
+        const VARIANT_COUNT: usize = 2;
+
+        #[inline]
+        unsafe fn from_ordinal_unsafe(number: Self::VariantType) -> Self {
+            Self::from_ordinal(number).unwrap()
+        }
+
+        #[inline]
+        fn from_ordinal(number: Self::VariantType) -> Option<Self> {
+            match number {
+                0 => Some(Self::A),
+                1 => Some(Self::B),
+                _ => None,
+            }
+        }
+
+        #[inline]
+        fn ordinal(&self) -> Self::VariantType {
+            match self {
+                Self::A => 0,
+                Self::B => 1,
+            }
+        }
+    }
+
+    assert_eq!(Some(MyEnum::A), MyEnum::from_variant_index(0));
+    assert_eq!(None, MyEnum::from_variant_index(1));
+    assert_eq!(None, MyEnum::from_bits(1));
+}
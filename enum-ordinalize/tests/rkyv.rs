@@ -0,0 +1,141 @@
+#![cfg(all(feature = "derive", feature = "rkyv"))]
+
+use enum_ordinalize::{rkyv::ArchivedOrdinal, Ordinalize};
+use rkyv::{rancor::Error, Archive, Archived, Deserialize, Serialize};
+
+#[derive(Debug, PartialEq, Eq, Ordinalize)]
+#[ordinalize(rkyv)]
+#[repr(u16)]
+enum Kind {
+    Header = 0x0102,
+    Body   = 0x0304,
+}
+
+#[derive(Debug, PartialEq, Eq, Ordinalize)]
+#[ordinalize(rkyv)]
+enum Sign {
+    Negative = -1,
+    Zero     = 0,
+    Positive = 1,
+}
+
+#[derive(Debug, PartialEq, Eq, Archive, Serialize, Deserialize)]
+#[rkyv(derive(Debug))]
+struct Record {
+    kind: Kind,
+    sign: Sign,
+    len:  u32,
+}
+
+#[test]
+fn archived_form() {
+    assert_eq!(core::mem::size_of::<u16>(), core::mem::size_of::<ArchivedOrdinal<Kind>>());
+
+    let bytes = rkyv::to_bytes::<Error>(&Kind::Body).unwrap();
+
+    assert_eq!(&0x0304u16.to_le_bytes(), bytes.as_slice());
+
+    let archived = rkyv::access::<Archived<Kind>, Error>(&bytes).unwrap();
+
+    assert_eq!(0x0304, archived.ordinal());
+    assert_eq!(Some(Kind::Body), archived.get());
+    assert_eq!(*archived, Kind::Body);
+}
+
+#[test]
+fn record() {
+    let record = Record {
+        kind: Kind::Header, sign: Sign::Negative, len: 7
+    };
+
+    let bytes = rkyv::to_bytes::<Error>(&record).unwrap();
+
+    let archived = rkyv::access::<Archived<Record>, Error>(&bytes).unwrap();
+
+    assert_eq!(archived.kind, Kind::Header);
+    assert_eq!(archived.sign, Sign::Negative);
+    assert_eq!(7, archived.len);
+
+    assert_eq!(record, rkyv::deserialize::<Record, Error>(archived).unwrap());
+}
+
+#[test]
+fn reject_corrupt() {
+    let record = Record {
+        kind: Kind::Header, sign: Sign::Positive, len: 7
+    };
+
+    let mut bytes = rkyv::to_bytes::<Error>(&record).unwrap();
+
+    assert!(rkyv::access::<Archived<Record>, Error>(&bytes).is_ok());
+
+    // `kind` is the first field, stored in little endian
+    bytes[0] = 0x05;
+
+    let error = rkyv::access::<Archived<Record>, Error>(&bytes).unwrap_err();
+
+    assert!(error.to_string().contains("is not the value of any variant"));
+
+    let archived = unsafe { rkyv::access_unchecked::<Archived<Record>>(&bytes) };
+
+    assert_eq!(None, archived.kind.get());
+    assert!(rkyv::deserialize::<Record, Error>(archived).is_err());
+}
+
+#[cfg(target_pointer_width = "64")]
+#[test]
+fn reject_out_of_range() {
+    #[allow(clippy::enum_clike_unportable_variant)]
+    #[derive(Debug, PartialEq, Eq, Ordinalize)]
+    #[ordinalize(rkyv)]
+    #[repr(usize)]
+    enum Offset {
+        Near = 1,
+        Far  = 0x1_0000_0001,
+    }
+
+    let bytes = rkyv::to_bytes::<Error>(&Offset::Near).unwrap();
+
+    assert_eq!(
+        Offset::Near,
+        rkyv::deserialize::<Offset, Error>(
+            rkyv::access::<Archived<Offset>, Error>(&bytes).unwrap()
+        )
+        .unwrap()
+    );
+
+    let error = rkyv::to_bytes::<Error>(&Offset::Far).unwrap_err();
+
+    assert!(error.to_string().contains("does not fit in the archived"));
+}
+
+#[test]
+fn own_implementation() {
+    // without the `rkyv` attribute, the enum can derive the traits of rkyv by itself
+    #[derive(Debug, PartialEq, Eq, Ordinalize, Archive, Serialize, Deserialize)]
+    enum MyEnum {
+        A,
+        B,
+    }
+
+    let bytes = rkyv::to_bytes::<Error>(&MyEnum::B).unwrap();
+
+    let archived = rkyv::access::<Archived<MyEnum>, Error>(&bytes).unwrap();
+
+    assert_eq!(MyEnum::B, rkyv::deserialize::<MyEnum, Error>(archived).unwrap());
+}
+
+#[test]
+fn generic() {
+    #[derive(Debug, PartialEq, Eq, Ordinalize)]
+    #[ordinalize(rkyv)]
+    #[repr(usize)]
+    enum Slot<const N: usize> {
+        A = 1,
+        B = 2,
+    }
+
+    let bytes = rkyv::to_bytes::<Error>(&Slot::<4>::B).unwrap();
+
+    assert_eq!(Slot::<4>::B, rkyv::from_bytes::<Slot<4>, Error>(&bytes).unwrap());
+}
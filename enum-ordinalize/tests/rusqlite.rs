@@ -0,0 +1,120 @@
+#![cfg(all(feature = "derive", feature = "rusqlite"))]
+
+use enum_ordinalize::{Ordinalize, UnknownOrdinalError, UnknownVariantNameError};
+use rusqlite::{Connection, Error};
+
+#[derive(Debug, PartialEq, Eq, Ordinalize)]
+#[ordinalize(sql = "value")]
+#[repr(u16)]
+enum Kind {
+    Header = 0x0102,
+    Body   = 0x0304,
+}
+
+#[derive(Debug, PartialEq, Eq, Ordinalize)]
+#[ordinalize(sql = "name")]
+enum Level {
+    Info = 2,
+    Warn = 1,
+}
+
+fn connection() -> Connection {
+    let connection = Connection::open_in_memory().unwrap();
+
+    connection.execute_batch("CREATE TABLE records (kind INTEGER, level TEXT)").unwrap();
+
+    connection
+}
+
+#[test]
+fn round_trip() {
+    let connection = connection();
+
+    connection
+        .execute("INSERT INTO records (kind, level) VALUES (?1, ?2)", (Kind::Body, Level::Warn))
+        .unwrap();
+
+    let (kind, level): (i64, String) = connection
+        .query_row("SELECT kind, level FROM records", [], |row| Ok((row.get(0)?, row.get(1)?)))
+        .unwrap();
+
+    assert_eq!(0x0304, kind);
+    assert_eq!("Warn", level);
+
+    let (kind, level): (Kind, Level) = connection
+        .query_row("SELECT kind, level FROM records", [], |row| Ok((row.get(0)?, row.get(1)?)))
+        .unwrap();
+
+    assert_eq!(Kind::Body, kind);
+    assert_eq!(Level::Warn, level);
+}
+
+#[test]
+fn unknown_value() {
+    let connection = connection();
+
+    connection.execute("INSERT INTO records (kind, level) VALUES (5, 'Error')", []).unwrap();
+
+    let error = connection
+        .query_row("SELECT kind FROM records", [], |row| row.get::<_, Kind>(0))
+        .unwrap_err();
+
+    match error {
+        Error::FromSqlConversionFailure(_, _, error) => {
+            let error = error.downcast_ref::<UnknownOrdinalError<Kind>>().unwrap();
+
+            assert_eq!(5, error.value());
+        },
+        error => panic!("{error:?}"),
+    }
+
+    let error = connection
+        .query_row("SELECT level FROM records", [], |row| row.get::<_, Level>(0))
+        .unwrap_err();
+
+    match error {
+        Error::FromSqlConversionFailure(_, _, error) => {
+            let error = error.downcast_ref::<UnknownVariantNameError<Level>>().unwrap();
+
+            assert_eq!("Error", error.name());
+        },
+        error => panic!("{error:?}"),
+    }
+}
+
+#[test]
+fn out_of_range() {
+    let connection = connection();
+
+    connection.execute("INSERT INTO records (kind) VALUES (-1)", []).unwrap();
+
+    let error = connection
+        .query_row("SELECT kind FROM records", [], |row| row.get::<_, Kind>(0))
+        .unwrap_err();
+
+    assert!(matches!(error, Error::IntegralValueOutOfRange(0, -1)), "{error:?}");
+}
+
+#[test]
+fn own_implementation() {
+    // without the `sql` attribute, the enum can implement the traits of rusqlite by itself
+    #[derive(Debug, PartialEq, Eq, Ordinalize)]
+    enum Flag {
+        Off,
+        On,
+    }
+
+    impl rusqlite::types::ToSql for Flag {
+        fn to_sql(&self) -> rusqlite::Result<rusqlite::types::ToSqlOutput<'_>> {
+            Ok(rusqlite::types::ToSqlOutput::from(*self == Flag::On))
+        }
+    }
+
+    let connection = connection();
+
+    connection.execute("INSERT INTO records (kind) VALUES (?1)", [Flag::On]).unwrap();
+
+    let kind: i64 = connection.query_row("SELECT kind FROM records", [], |row| row.get(0)).unwrap();
+
+    assert_eq!(1, kind);
+}
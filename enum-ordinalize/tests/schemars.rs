@@ -0,0 +1,70 @@
+#![cfg(all(feature = "derive", feature = "schemars"))]
+
+use enum_ordinalize::Ordinalize;
+use schemars::{schema_for, JsonSchema};
+use serde_json::json;
+
+#[derive(Ordinalize)]
+#[ordinalize(schema = "value")]
+enum Sign {
+    Negative = -1,
+    Zero     = 0,
+    Positive = 1,
+}
+
+#[derive(Ordinalize)]
+#[ordinalize(schema = "name")]
+enum Level {
+    Info  = 2,
+    Warn  = 1,
+    r#Err = 0,
+}
+
+#[allow(dead_code)]
+#[derive(JsonSchema)]
+struct Record {
+    sign:  Sign,
+    level: Option<Level>,
+}
+
+#[test]
+fn value_schema() {
+    let schema = schema_for!(Sign);
+
+    assert_eq!(Some(&json!("integer")), schema.get("type"));
+    assert_eq!(Some(&json!([-1, 0, 1])), schema.get("enum"));
+    assert_eq!(Some(&json!(["Negative", "Zero", "Positive"])), schema.get("x-enum-varnames"));
+}
+
+#[test]
+fn name_schema() {
+    let schema = schema_for!(Level);
+
+    assert_eq!(Some(&json!("string")), schema.get("type"));
+    assert_eq!(Some(&json!(["Info", "Warn", "Err"])), schema.get("enum"));
+    assert_eq!(None, schema.get("x-enum-varnames"));
+}
+
+#[test]
+fn referenced_schema() {
+    let schema = schema_for!(Record);
+
+    assert_eq!(Some(&json!("#/$defs/Sign")), schema.pointer("/properties/sign/$ref"));
+    assert_eq!(Some(&json!(["Info", "Warn", "Err"])), schema.pointer("/$defs/Level/enum"));
+}
+
+#[test]
+fn own_implementation() {
+    // without the `schema` attribute, the enum can derive `JsonSchema` by itself
+    #[allow(dead_code)]
+    #[derive(Ordinalize, schemars::JsonSchema)]
+    enum Flag {
+        Off,
+        On,
+    }
+
+    let schema = schema_for!(Flag);
+
+    assert_eq!(Some(&json!("string")), schema.get("type"));
+    assert_eq!(Some(&json!(["Off", "On"])), schema.get("enum"));
+}
@@ -0,0 +1,112 @@
+#![cfg(all(feature = "derive", feature = "traits", feature = "alloc"))]
+
+use enum_ordinalize::{
+    group_by_variant, sort_by_key_variant, sort_by_key_variant_value, sort_by_variant,
+    sort_by_variant_value, Ordinalize,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Ordinalize)]
+#[repr(i16)]
+enum Kind {
+    Click  = 30,
+    Scroll = -5,
+    Resize = 7,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct Event {
+    kind: Kind,
+    id:   u32,
+}
+
+fn events() -> Vec<Event> {
+    [Kind::Resize, Kind::Click, Kind::Scroll, Kind::Click, Kind::Resize, Kind::Scroll]
+        .into_iter()
+        .enumerate()
+        .map(|(id, kind)| Event {
+            kind,
+            id: id as u32,
+        })
+        .collect()
+}
+
+fn ids(events: &[Event]) -> Vec<u32> {
+    events.iter().map(|event| event.id).collect()
+}
+
+#[test]
+fn sort_variants() {
+    let mut kinds = [Kind::Resize, Kind::Scroll, Kind::Click, Kind::Resize, Kind::Click];
+
+    sort_by_variant(&mut kinds);
+
+    assert_eq!([Kind::Click, Kind::Click, Kind::Scroll, Kind::Resize, Kind::Resize], kinds);
+
+    sort_by_variant_value(&mut kinds);
+
+    assert_eq!([Kind::Scroll, Kind::Resize, Kind::Resize, Kind::Click, Kind::Click], kinds);
+
+    let mut empty: [Kind; 0] = [];
+
+    sort_by_variant(&mut empty);
+}
+
+#[test]
+fn sort_by_key_is_stable() {
+    let mut events = events();
+
+    sort_by_key_variant(&mut events, |event| event.kind);
+
+    assert_eq!(vec![1, 3, 2, 5, 0, 4], ids(&events));
+
+    sort_by_key_variant_value(&mut events, |event| event.kind);
+
+    assert_eq!(vec![2, 5, 0, 4, 1, 3], ids(&events));
+}
+
+#[test]
+fn sort_large() {
+    let mut events: Vec<Event> = (0..10_000)
+        .map(|id| Event {
+            kind: Kind::VARIANTS[(id * 7 + id / 3) as usize % 3],
+            id,
+        })
+        .collect();
+
+    let mut expected: Vec<u32> = ids(&events);
+
+    expected.sort_by_key(|id| events[*id as usize].kind.variant_index());
+
+    sort_by_key_variant(&mut events, |event| event.kind);
+
+    assert_eq!(expected, ids(&events));
+}
+
+#[test]
+fn group() {
+    let mut events = events();
+
+    let mut groups = group_by_variant(&mut events, |event| event.kind);
+
+    assert_eq!(vec![1, 3], ids(groups.get(&Kind::Click)));
+    assert_eq!(vec![2, 5], ids(groups.get(&Kind::Scroll)));
+
+    groups.get_mut(&Kind::Resize).reverse();
+
+    assert_eq!(
+        vec![(Kind::Click, 2), (Kind::Scroll, 2), (Kind::Resize, 2)],
+        groups.iter().map(|(kind, events)| (kind, events.len())).collect::<Vec<_>>()
+    );
+    assert_eq!(vec![1, 3, 2, 5, 4, 0], ids(groups.into_slice()));
+}
+
+#[test]
+fn group_missing_variants() {
+    let mut kinds = [Kind::Scroll, Kind::Scroll];
+
+    let groups = group_by_variant(&mut kinds, |kind| *kind);
+
+    assert!(groups.get(&Kind::Click).is_empty());
+    assert_eq!(&[Kind::Scroll, Kind::Scroll], groups.get(&Kind::Scroll));
+    assert_eq!("{Click: [], Scroll: [Scroll, Scroll], Resize: []}", format!("{groups:?}"));
+}
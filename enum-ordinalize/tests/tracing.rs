@@ -0,0 +1,75 @@
+#![cfg(all(feature = "derive", feature = "tracing"))]
+
+use std::{
+    fmt::Debug,
+    sync::{Arc, Mutex},
+};
+
+use enum_ordinalize::{tracing::record, Ordinalize};
+use tracing::{
+    field::{Empty, Field, Visit},
+    span::{Attributes, Id, Record},
+    Event, Metadata, Subscriber,
+};
+
+#[derive(Ordinalize)]
+#[repr(u16)]
+enum Kind {
+    Header = 0x0102,
+    Body   = 0x0304,
+}
+
+#[derive(Clone, Default)]
+struct Recorder(Arc<Mutex<Vec<String>>>);
+
+impl Visit for Recorder {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.0.lock().unwrap().push(format!("{field}: {value:?}"));
+    }
+
+    fn record_u64(&mut self, field: &Field, value: u64) {
+        self.0.lock().unwrap().push(format!("{field}: {value}"));
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+        self.0.lock().unwrap().push(format!("{field}: {value:?}"));
+    }
+}
+
+impl Subscriber for Recorder {
+    fn enabled(&self, _metadata: &Metadata<'_>) -> bool {
+        true
+    }
+
+    fn new_span(&self, _span: &Attributes<'_>) -> Id {
+        Id::from_u64(1)
+    }
+
+    fn record(&self, _span: &Id, values: &Record<'_>) {
+        values.record(&mut self.clone());
+    }
+
+    fn record_follows_from(&self, _span: &Id, _follows: &Id) {}
+
+    fn event(&self, event: &Event<'_>) {
+        event.record(&mut self.clone());
+    }
+
+    fn enter(&self, _span: &Id) {}
+
+    fn exit(&self, _span: &Id) {}
+}
+
+#[test]
+fn record_span_fields() {
+    let recorder = Recorder::default();
+
+    tracing::subscriber::with_default(recorder.clone(), || {
+        let span = tracing::info_span!("request", kind = Empty, kind.ordinal = Empty);
+
+        record(&span, "kind", &Kind::Body);
+        record(&span, "other", &Kind::Header);
+    });
+
+    assert_eq!(vec!["kind: \"Body\"", "kind.ordinal: 772"], *recorder.0.lock().unwrap());
+}
@@ -0,0 +1,92 @@
+#![cfg(all(feature = "derive", feature = "utoipa"))]
+
+use enum_ordinalize::Ordinalize;
+use serde_json::{json, Value};
+use utoipa::{OpenApi, PartialSchema, ToSchema};
+
+#[derive(Ordinalize)]
+#[ordinalize(schema = "value")]
+#[repr(u16)]
+enum Kind {
+    Header = 0x0102,
+    Body   = 0x0304,
+}
+
+#[derive(Ordinalize)]
+#[ordinalize(schema = "name")]
+enum Level {
+    Info = 2,
+    Warn = 1,
+}
+
+#[allow(dead_code)]
+#[derive(ToSchema)]
+struct Record {
+    kind:  Kind,
+    level: Level,
+}
+
+fn to_value<T: PartialSchema>() -> Value {
+    serde_json::to_value(T::schema()).unwrap()
+}
+
+#[test]
+fn value_schema() {
+    assert_eq!("Kind", Kind::name());
+
+    assert_eq!(
+        json!({
+            "type": "integer",
+            "enum": [0x0102, 0x0304],
+            "x-enum-varnames": ["Header", "Body"],
+        }),
+        to_value::<Kind>()
+    );
+}
+
+#[test]
+fn name_schema() {
+    assert_eq!("Level", Level::name());
+
+    assert_eq!(
+        json!({
+            "type": "string",
+            "enum": ["Info", "Warn"],
+        }),
+        to_value::<Level>()
+    );
+}
+
+#[test]
+fn referenced_schema() {
+    #[derive(OpenApi)]
+    #[openapi(components(schemas(Record, Kind, Level)))]
+    struct ApiDoc;
+
+    let document = serde_json::to_value(ApiDoc::openapi()).unwrap();
+
+    assert_eq!(
+        Some(&json!("#/components/schemas/Kind")),
+        document.pointer("/components/schemas/Record/properties/kind/$ref")
+    );
+    assert_eq!(Some(&json!([0x0102, 0x0304])), document.pointer("/components/schemas/Kind/enum"));
+}
+
+#[test]
+fn own_implementation() {
+    // without the `schema` attribute, the enum can derive `ToSchema` by itself
+    #[allow(dead_code)]
+    #[derive(Ordinalize, ToSchema)]
+    enum Flag {
+        Off,
+        On,
+    }
+
+    assert_eq!(
+        json!({
+            "type": "string",
+            "enum": ["Off", "On"],
+        }),
+        to_value::<Flag>()
+    );
+}
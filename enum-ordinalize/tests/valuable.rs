@@ -0,0 +1,101 @@
+#![cfg(all(feature = "derive", feature = "valuable"))]
+
+use enum_ordinalize::Ordinalize;
+use valuable::{Enumerable, Fields, NamedValues, Valuable, Value, Variant, Visit};
+
+#[derive(Ordinalize)]
+#[ordinalize(valuable)]
+enum Sign {
+    Negative = -1,
+    Positive = 1,
+}
+
+#[derive(Default)]
+struct Collector(Vec<String>);
+
+impl Visit for Collector {
+    fn visit_value(&mut self, value: Value<'_>) {
+        if let Value::Enumerable(enumerable) = value {
+            self.0.push(enumerable.variant().name().to_string());
+
+            enumerable.visit(self);
+        }
+    }
+
+    fn visit_named_fields(&mut self, _named_values: &NamedValues<'_>) {
+        unreachable!()
+    }
+
+    fn visit_unnamed_fields(&mut self, values: &[Value<'_>]) {
+        for value in values {
+            assert!(matches!(value, Value::I8(_)));
+
+            self.0.push(format!("{value:?}"));
+        }
+    }
+}
+
+#[test]
+fn definition() {
+    let definition = Sign::Positive.definition();
+
+    assert_eq!("Sign", definition.name());
+
+    let variants: Vec<&str> = definition.variants().iter().map(|variant| variant.name()).collect();
+    assert_eq!(vec!["Negative", "Positive"], variants);
+
+    assert!(definition
+        .variants()
+        .iter()
+        .all(|variant| matches!(variant.fields(), Fields::Unnamed(1))));
+
+    assert!(
+        matches!(Sign::Negative.variant(), Variant::Static(variant) if variant.name() == "Negative")
+    );
+}
+
+#[test]
+fn visit() {
+    let mut collector = Collector::default();
+
+    valuable::visit(&Sign::Negative, &mut collector);
+    valuable::visit(&Sign::Positive.as_value(), &mut collector);
+
+    assert_eq!(vec!["Negative", "-1", "Positive", "1"], collector.0);
+}
+
+#[test]
+fn own_implementation() {
+    // without the `valuable` attribute, the enum can implement `Valuable` by itself
+    #[derive(PartialEq, Eq, Ordinalize)]
+    enum Flag {
+        Off,
+        On,
+    }
+
+    impl Valuable for Flag {
+        fn as_value(&self) -> Value<'_> {
+            Value::Bool(*self == Flag::On)
+        }
+
+        fn visit(&self, visitor: &mut dyn Visit) {
+            visitor.visit_value(self.as_value());
+        }
+    }
+
+    assert!(matches!(Flag::On.as_value(), Value::Bool(true)));
+    assert!(matches!(Flag::Off.as_value(), Value::Bool(false)));
+}
+
+#[test]
+fn generic() {
+    #[derive(Ordinalize)]
+    #[ordinalize(valuable)]
+    enum Slot<const N: usize> {
+        A,
+        B,
+    }
+
+    assert_eq!("Slot", Slot::<4>::B.definition().name());
+    assert_eq!("B", Slot::<4>::B.variant().name());
+}
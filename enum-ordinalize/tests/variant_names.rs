@@ -0,0 +1,90 @@
+#![cfg(all(feature = "derive", feature = "traits"))]
+
+use enum_ordinalize::{Ordinalize, VariantNames};
+
+#[test]
+fn derive() {
+    #[derive(Debug, PartialEq, Eq, Ordinalize)]
+    enum Keyword {
+        Let   = 3,
+        r#Fn  = 1,
+        Match = 2,
+    }
+
+    assert_eq!(["Let", "Fn", "Match"], Keyword::VARIANT_NAMES);
+
+    assert_eq!("Let", Keyword::Let.variant_name());
+    assert_eq!("Fn", Keyword::r#Fn.variant_name());
+
+    assert_eq!(Some(Keyword::Match), Keyword::from_variant_name("Match"));
+    assert_eq!(Some(Keyword::r#Fn), Keyword::from_variant_name("Fn"));
+    assert_eq!(None, Keyword::from_variant_name("match"));
+}
+
+#[test]
+fn macro_rules() {
+    enum_ordinalize::ordinalize! {
+        #[derive(Debug, PartialEq, Eq)]
+        #[repr(u8)]
+        enum Keyword {
+            Let = 3,
+            Fn = 1,
+        }
+    }
+
+    assert_eq!(["Let", "Fn"], Keyword::VARIANT_NAMES);
+    assert_eq!("Fn", Keyword::Fn.variant_name());
+    assert_eq!(Some(Keyword::Let), Keyword::from_variant_name("Let"));
+}
+
+#[test]
+fn raw_identifiers() {
+    #[allow(non_camel_case_types)]
+    #[derive(Debug, PartialEq, Eq, Ordinalize)]
+    enum Derived {
+        r#type,
+        Other,
+    }
+
+    enum_ordinalize::ordinalize! {
+        #[allow(non_camel_case_types)]
+        #[derive(Debug, PartialEq, Eq)]
+        #[repr(u8)]
+        enum Declared {
+            r#type,
+            Other,
+        }
+    }
+
+    assert_eq!(["type", "Other"], Derived::VARIANT_NAMES);
+    assert_eq!(Derived::VARIANT_NAMES, Declared::VARIANT_NAMES);
+    assert_eq!("type", Declared::r#type.variant_name());
+    assert_eq!(Some(Declared::r#type), Declared::from_variant_name("type"));
+}
+
+mod reexported {
+    pub use enum_ordinalize::*;
+}
+
+#[test]
+fn crate_path() {
+    #[derive(Debug, PartialEq, Eq, Ordinalize)]
+    #[ordinalize(crate = crate::reexported, protobuf)]
+    #[repr(i32)]
+    enum Keyword {
+        Let   = 3,
+        Match = 2,
+    }
+
+    #[derive(Debug, PartialEq, Eq, Ordinalize)]
+    #[ordinalize(crate = "crate::reexported")]
+    enum Literal {
+        True,
+        False,
+    }
+
+    assert_eq!(["Let", "Match"], Keyword::VARIANT_NAMES);
+    assert_eq!(Err(reexported::UnknownEnumValue(1)), Keyword::try_from(1));
+
+    assert_eq!("False", Literal::False.variant_name());
+}